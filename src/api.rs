@@ -0,0 +1,115 @@
+use crate::{bot::Bot, voter::Voter, Client, Result};
+
+#[allow(deprecated)]
+use crate::Stats;
+
+/// An async trait describing the [Top.gg API](https://docs.top.gg) operations available through a [`Client`].
+///
+/// Accepting an `impl TopggApi` (or a `dyn TopggApi`) instead of a concrete [`Client`] lets your own code be tested against a test double, like the [`MockClient`][crate::mock::MockClient] available with the `mock` feature.
+///
+/// It's described as follows (without [`async_trait`]'s macro expansion):
+/// ```rust,ignore
+/// #[async_trait::async_trait]
+/// pub trait TopggApi: Send + Sync {
+///   async fn get_bot(&self, id: u64) -> Result<Bot>;
+///   async fn get_bot_stats(&self, id: u64) -> Result<Option<usize>>;
+///   async fn get_stats(&self) -> Result<Stats>;
+///   async fn post_stats(&self, new_stats: &Stats) -> Result<()>;
+///   async fn get_voters(&self) -> Result<Vec<Voter>>;
+///   async fn get_vote_count(&self, user_id: u64) -> Result<u32>;
+///   async fn has_voted(&self, user_id: u64) -> Result<bool>;
+///   async fn is_weekend(&self) -> Result<bool>;
+/// }
+/// ```
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::TopggApi;
+///
+/// async fn reward(api: &impl TopggApi, user_id: u64) -> topgg::Result<bool> {
+///   Ok(api.has_voted(user_id).await? && !api.is_weekend().await?)
+/// }
+/// ```
+#[async_trait::async_trait]
+pub trait TopggApi: Send + Sync {
+  /// Fetches a listed bot from a Discord ID. (See [`Client::get_bot`])
+  async fn get_bot(&self, id: u64) -> Result<Bot>;
+
+  /// Fetches the server count of any listed bot from its Discord ID. (See [`Client::get_bot_stats`])
+  async fn get_bot_stats(&self, id: u64) -> Result<Option<usize>>;
+
+  /// Fetches your bot's posted statistics. (See [`Client::get_stats`])
+  #[allow(deprecated)]
+  async fn get_stats(&self) -> Result<Stats>;
+
+  /// Posts your bot's statistics. (See [`Client::post_stats`])
+  #[allow(deprecated)]
+  async fn post_stats(&self, new_stats: &Stats) -> Result<()>;
+
+  /// Fetches your bot's last 1000 voters. (See [`Client::get_voters`])
+  async fn get_voters(&self) -> Result<Vec<Voter>>;
+
+  /// Retrieves the amount of times the specified user has voted your bot within the current voting window. (See [`Client::get_vote_count`])
+  async fn get_vote_count(&self, user_id: u64) -> Result<u32>;
+
+  /// Checks if the specified user has voted your bot. (See [`Client::has_voted`])
+  ///
+  /// By default, this checks if [`get_vote_count`][TopggApi::get_vote_count] is non-zero.
+  async fn has_voted(&self, user_id: u64) -> Result<bool> {
+    self
+      .get_vote_count(user_id)
+      .await
+      .map(|vote_count| vote_count != 0)
+  }
+
+  /// Checks if the weekend multiplier is active. (See [`Client::is_weekend`])
+  async fn is_weekend(&self) -> Result<bool>;
+}
+
+#[async_trait::async_trait]
+impl TopggApi for Client {
+  #[inline(always)]
+  async fn get_bot(&self, id: u64) -> Result<Bot> {
+    Client::get_bot(self, id).await
+  }
+
+  #[inline(always)]
+  async fn get_bot_stats(&self, id: u64) -> Result<Option<usize>> {
+    Client::get_bot_stats(self, id).await
+  }
+
+  #[inline(always)]
+  #[allow(deprecated)]
+  async fn get_stats(&self) -> Result<Stats> {
+    Client::get_stats(self).await
+  }
+
+  #[inline(always)]
+  #[allow(deprecated)]
+  async fn post_stats(&self, new_stats: &Stats) -> Result<()> {
+    Client::post_stats(self, new_stats).await
+  }
+
+  #[inline(always)]
+  async fn get_voters(&self) -> Result<Vec<Voter>> {
+    Client::get_voters(self).await
+  }
+
+  #[inline(always)]
+  async fn get_vote_count(&self, user_id: u64) -> Result<u32> {
+    Client::get_vote_count(self, user_id).await
+  }
+
+  #[inline(always)]
+  async fn has_voted(&self, user_id: u64) -> Result<bool> {
+    Client::has_voted(self, user_id).await
+  }
+
+  #[inline(always)]
+  async fn is_weekend(&self) -> Result<bool> {
+    Client::is_weekend(self).await
+  }
+}
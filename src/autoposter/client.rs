@@ -1,4 +1,4 @@
-use crate::InnerClient;
+use crate::{ClientBuilder, InnerClient};
 use std::sync::Arc;
 
 pub trait AsClientSealed {
@@ -7,14 +7,47 @@ pub trait AsClientSealed {
 
 /// A private trait that represents any datatype that can be interpreted as a [Top.gg API](https://docs.top.gg) Client.
 ///
-/// This can either be a reference to an existing [`Client`][crate::Client] or a [`&str`][std::str] representing a [Top.gg API](https://docs.top.gg) token.
+/// This can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token.
 pub trait AsClient: AsClientSealed {}
 
+impl<T> AsClientSealed for &T
+where
+  T: AsClientSealed + ?Sized,
+{
+  #[inline(always)]
+  fn as_client(&self) -> Arc<InnerClient> {
+    (**self).as_client()
+  }
+}
+
+impl<T> AsClient for &T where T: AsClient + ?Sized {}
+
+impl<T> AsClientSealed for Arc<T>
+where
+  T: AsClientSealed + ?Sized,
+{
+  #[inline(always)]
+  fn as_client(&self) -> Arc<InnerClient> {
+    (**self).as_client()
+  }
+}
+
+impl<T> AsClient for Arc<T> where T: AsClient + ?Sized {}
+
 impl AsClientSealed for str {
   #[inline(always)]
   fn as_client(&self) -> Arc<InnerClient> {
-    Arc::new(InnerClient::new(String::from(self)))
+    ClientBuilder::new(String::from(self)).build().as_client()
   }
 }
 
 impl AsClient for str {}
+
+impl AsClientSealed for String {
+  #[inline(always)]
+  fn as_client(&self) -> Arc<InnerClient> {
+    self.as_str().as_client()
+  }
+}
+
+impl AsClient for String {}
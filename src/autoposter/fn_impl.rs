@@ -0,0 +1,58 @@
+use crate::autoposter::{Handler, SharedStats};
+use core::{future::Future, time::Duration};
+use std::sync::Arc;
+use tokio::{
+  task::{spawn, JoinHandle},
+  time::sleep,
+};
+
+#[allow(deprecated)]
+use crate::Stats;
+
+/// A built-in [`Handler`] that retrieves the bot's [`Stats`] by calling your own async closure in intervals.
+///
+/// This is useful for bots that don't use any of the supported libraries and track their server count through their own means.
+pub struct FnHandler {
+  stats: Arc<SharedStats>,
+  thread: JoinHandle<()>,
+}
+
+impl FnHandler {
+  #[allow(deprecated)]
+  pub(super) fn new<F, R, S>(interval: Duration, f: F) -> Self
+  where
+    F: Fn() -> R + Send + Sync + 'static,
+    R: Future<Output = S> + Send,
+    S: Into<Stats>,
+  {
+    let stats = Arc::new(SharedStats::new());
+    let writer = Arc::clone(&stats);
+
+    Self {
+      stats,
+      thread: spawn(async move {
+        loop {
+          let new_stats = f().await.into();
+
+          writer.write().await.replace(new_stats);
+
+          sleep(interval).await;
+        }
+      }),
+    }
+  }
+}
+
+impl Handler for FnHandler {
+  #[inline(always)]
+  fn stats(&self) -> &SharedStats {
+    &self.stats
+  }
+}
+
+impl Drop for FnHandler {
+  #[inline(always)]
+  fn drop(&mut self) {
+    self.thread.abort();
+  }
+}
@@ -1,19 +1,33 @@
-use crate::{Result, Stats};
+use crate::{Error, InnerClient, Result};
 use core::{
+  fmt,
+  future::Future,
+  mem,
   ops::{Deref, DerefMut},
   time::Duration,
 };
-use std::sync::Arc;
+use std::{
+  sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc, Mutex,
+  },
+  time::Instant,
+};
 use tokio::{
-  sync::{mpsc, RwLock, RwLockWriteGuard, Semaphore},
+  sync::{mpsc, Notify, RwLock, RwLockWriteGuard, Semaphore},
   task::{spawn, JoinHandle},
   time::sleep,
 };
 
+#[allow(deprecated)]
+use crate::Stats;
+
 mod client;
+mod fn_impl;
 
 pub use client::AsClient;
 pub(crate) use client::AsClientSealed;
+pub use fn_impl::FnHandler;
 
 cfg_if::cfg_if! {
   if #[cfg(feature = "serenity")] {
@@ -33,21 +47,45 @@ cfg_if::cfg_if! {
   }
 }
 
+/// The minimum interval allowed between each of the [`Autoposter`]'s posts, which is 15 minutes.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(900);
+
+/// An error returned when an [`Autoposter`]'s interval is shorter than [`MIN_INTERVAL`]. (See [`Autoposter::try_new`])
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntervalTooShort;
+
+impl fmt::Display for IntervalTooShort {
+  #[inline(always)]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "the interval mustn't be shorter than 15 minutes")
+  }
+}
+
+impl std::error::Error for IntervalTooShort {}
+
 /// A struct representing a thread-safe form of the [`Stats`] struct to be used in autoposter [`Handler`]s.
+#[allow(deprecated)]
 pub struct SharedStats {
   sem: Semaphore,
   stats: RwLock<Stats>,
+  last_posted: Mutex<Option<Stats>>,
+  always_post: AtomicBool,
+  fed: AtomicBool,
 }
 
 /// A guard wrapping over tokio's [`RwLockWriteGuard`] that lets you freely feed new [`Stats`] data before being sent to the [`Autoposter`].
+///
+/// Once dropped, the [`Autoposter`] is notified only if the [`Stats`] inside changed since its last successful post. (See [`SharedStats::always_post`])
+#[allow(deprecated)]
 pub struct SharedStatsGuard<'a> {
-  sem: &'a Semaphore,
+  shared: &'a SharedStats,
   guard: RwLockWriteGuard<'a, Stats>,
 }
 
 impl SharedStatsGuard<'_> {
   /// Directly replaces the current [`Stats`] inside with the other.
   #[inline(always)]
+  #[allow(deprecated)]
   pub fn replace(&mut self, other: Stats) {
     let ref_mut = self.guard.deref_mut();
     *ref_mut = other;
@@ -55,6 +93,7 @@ impl SharedStatsGuard<'_> {
 
   /// Sets the current [`Stats`] server count.
   #[inline(always)]
+  #[allow(deprecated)]
   pub fn set_server_count(&mut self, server_count: usize) {
     self.guard.server_count = Some(server_count);
   }
@@ -66,6 +105,7 @@ impl SharedStatsGuard<'_> {
   pub fn set_shard_count(&mut self, _shard_count: usize) {}
 }
 
+#[allow(deprecated)]
 impl Deref for SharedStatsGuard<'_> {
   type Target = Stats;
 
@@ -83,10 +123,20 @@ impl DerefMut for SharedStatsGuard<'_> {
 }
 
 impl Drop for SharedStatsGuard<'_> {
-  #[inline(always)]
   fn drop(&mut self) {
-    if self.sem.available_permits() < 1 {
-      self.sem.add_permits(1);
+    self.shared.fed.store(true, Ordering::Relaxed);
+
+    let changed = self.shared.always_post.load(Ordering::Relaxed)
+      || self
+        .shared
+        .last_posted
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_none_or(|last_posted| *last_posted != *self.guard);
+
+    if changed && self.shared.sem.available_permits() < 1 {
+      self.shared.sem.add_permits(1);
     }
   }
 }
@@ -94,10 +144,14 @@ impl Drop for SharedStatsGuard<'_> {
 impl SharedStats {
   /// Creates a new [`SharedStats`] struct. Before any modifications, the [`Stats`] struct inside defaults to zero server count.
   #[inline(always)]
+  #[allow(deprecated)]
   pub fn new() -> Self {
     Self {
       sem: Semaphore::const_new(0),
       stats: RwLock::new(Stats::from(0)),
+      last_posted: Mutex::new(None),
+      always_post: AtomicBool::new(false),
+      fed: AtomicBool::new(false),
     }
   }
 
@@ -105,78 +159,325 @@ impl SharedStats {
   #[inline(always)]
   pub async fn write<'a>(&'a self) -> SharedStatsGuard<'a> {
     SharedStatsGuard {
-      sem: &self.sem,
+      shared: self,
       guard: self.stats.write().await,
     }
   }
 
+  /// Makes the [`Autoposter`] post every time this [`SharedStats`] is written to, even if the [`Stats`] inside didn't change since the last successful post. By default, unchanged [`Stats`] are not posted again.
   #[inline(always)]
-  async fn wait(&self) {
+  pub fn always_post(&self, always_post: bool) {
+    self.always_post.store(always_post, Ordering::Relaxed);
+  }
+
+  #[inline(always)]
+  pub(crate) async fn wait(&self) {
     self.sem.acquire().await.unwrap().forget();
   }
+
+  // checks if any stats have been fed yet, consuming the pending notification so the same stats aren't posted twice in a row.
+  pub(crate) fn take_fed(&self) -> bool {
+    if let Ok(permit) = self.sem.try_acquire() {
+      permit.forget();
+    }
+
+    self.fed.load(Ordering::Relaxed)
+  }
+}
+
+impl Default for SharedStats {
+  #[inline(always)]
+  fn default() -> Self {
+    Self::new()
+  }
 }
 
 /// A trait for handling events from third-party bot libraries.
 ///
 /// The struct implementing this trait should own an [`SharedStats`] struct and update it accordingly whenever Discord updates them with new data regarding guild/shard count.
+///
+/// This trait is also implemented for [`SharedStats`] itself and for any [`Arc`] of a [`Handler`], so an [`Arc<SharedStats>`][SharedStats] can be passed to the [`Autoposter`] while other parts of your application keep updating it through their own clones.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use core::time::Duration;
+/// use std::sync::Arc;
+/// use topgg::{Autoposter, Client, SharedStats};
+///
+/// # async fn example(client: Client) {
+/// let stats = Arc::new(SharedStats::new());
+/// let autoposter = Autoposter::new(&client, Arc::clone(&stats), Duration::from_secs(1800));
+///
+/// // somewhere else in your application...
+/// stats.write().await.set_server_count(12345);
+/// # }
+/// ```
 pub trait Handler: Send + Sync + 'static {
   /// The method that borrows [`SharedStats`] to the [`Autoposter`].
   fn stats(&self) -> &SharedStats;
 }
 
+impl Handler for SharedStats {
+  #[inline(always)]
+  fn stats(&self) -> &SharedStats {
+    self
+  }
+}
+
+impl<H> Handler for Arc<H>
+where
+  H: Handler + ?Sized,
+{
+  #[inline(always)]
+  fn stats(&self) -> &SharedStats {
+    (**self).stats()
+  }
+}
+
+struct Metrics {
+  started_at: Instant,
+  // nanoseconds since started_at plus one, or zero if nothing has been posted yet.
+  last_post: AtomicU64,
+  successes: AtomicU64,
+  failures: AtomicU64,
+}
+
+impl Metrics {
+  #[inline(always)]
+  fn new() -> Self {
+    Self {
+      started_at: Instant::now(),
+      last_post: AtomicU64::new(0),
+      successes: AtomicU64::new(0),
+      failures: AtomicU64::new(0),
+    }
+  }
+
+  fn record(&self, success: bool) {
+    let elapsed = self.started_at.elapsed().as_nanos() as u64;
+
+    self.last_post.store(elapsed + 1, Ordering::Relaxed);
+
+    if success {
+      &self.successes
+    } else {
+      &self.failures
+    }
+    .fetch_add(1, Ordering::Relaxed);
+  }
+}
+
 /// A struct that lets you automate the process of posting bot statistics to [Top.gg](https://top.gg) in intervals.
 ///
-/// **NOTE:** This struct owns the thread handle that executes the automatic posting. The autoposter thread will stop once this struct is dropped.
+/// If a post gets ratelimited and the ratelimit outlasts the interval, the next post waits until the ratelimit is lifted instead.
+///
+/// **NOTE:** This struct owns the thread handle that executes the automatic posting. The autoposter thread will stop once this struct is dropped, cancelling any post that is still in-flight. See [`shutdown`][Autoposter::shutdown] for stopping it gracefully.
 #[must_use]
+#[allow(deprecated)]
 pub struct Autoposter<H> {
   handler: Arc<H>,
+  metrics: Arc<Metrics>,
+  shutdown: Arc<Notify>,
   thread: JoinHandle<()>,
-  receiver: Option<mpsc::UnboundedReceiver<Result<()>>>,
+  receiver: Option<mpsc::UnboundedReceiver<Result<Stats>>>,
+  last_received: Option<Stats>,
+  stopped: bool,
 }
 
-impl<H> Autoposter<H>
+type ErrorCallback = Box<dyn FnMut(&Error) + Send>;
+
+/// A struct for configuring an [`Autoposter`] before starting it. See [`Autoposter::builder`].
+#[must_use]
+pub struct AutoposterBuilder<H> {
+  client: Arc<InnerClient>,
+  handler: H,
+  interval: Duration,
+  post_immediately: bool,
+  on_error: Option<ErrorCallback>,
+}
+
+impl<H> AutoposterBuilder<H>
 where
   H: Handler,
 {
-  /// Creates an [`Autoposter`] struct as well as immediately starting the thread. The thread will never stop until this struct gets dropped.
-  ///
-  /// - `client` can either be a reference to an existing [`Client`][crate::Client] or a [`&str`][std::str] representing a [Top.gg API](https://docs.top.gg) token.
-  /// - `handler` is a struct that handles the *retrieving stats* part before being sent to the [`Autoposter`]. This datatype is essentially the bridge between an external third-party bot library between this library.
-  ///
-  /// # Panics
+  /// Posts the [`Handler`]'s current stats as soon as the [`Autoposter`] starts if it has already fed any, even if they didn't change since their last post, like when reusing a [`SharedStats`]. Otherwise, the first post still happens as soon as the [`Handler`] feeds its first stats, so placeholder stats are never posted. Subsequent posts follow the normal interval. This defaults to `false`.
+  #[inline(always)]
+  pub fn post_immediately(mut self, post_immediately: bool) -> Self {
+    self.post_immediately = post_immediately;
+    self
+  }
+
+  /// Registers a callback that gets called every time the [`Autoposter`] fails to post the bot's stats, so failures don't go unnoticed without polling [`recv`][Autoposter::recv].
   ///
-  /// Panics if the interval argument is shorter than 15 minutes (900 seconds).
-  pub fn new<C>(client: &C, handler: H, interval: Duration) -> Self
+  /// This callback is called from the autoposter's thread, so it shouldn't block for long. The error is still sent to [`recv`][Autoposter::recv] afterwards.
+  #[inline(always)]
+  pub fn on_error<F>(mut self, on_error: F) -> Self
   where
-    C: AsClient,
+    F: FnMut(&Error) + Send + 'static,
   {
-    assert!(
-      interval.as_secs() >= 900,
-      "The interval mustn't be shorter than 15 minutes."
-    );
+    self.on_error = Some(Box::new(on_error));
+    self
+  }
+
+  /// Creates the [`Autoposter`] struct as well as immediately starting the thread. The thread will never stop until the [`Autoposter`] gets dropped or [shut down][Autoposter::shutdown].
+  pub fn build(self) -> Autoposter<H> {
+    let Self {
+      client,
+      handler,
+      interval,
+      mut post_immediately,
+      mut on_error,
+    } = self;
 
-    let client = client.as_client();
     let handler = Arc::new(handler);
+    let metrics = Arc::new(Metrics::new());
+    let shutdown = Arc::new(Notify::new());
     let (sender, receiver) = mpsc::unbounded_channel();
 
-    Self {
+    Autoposter {
       handler: Arc::clone(&handler),
+      metrics: Arc::clone(&metrics),
+      shutdown: Arc::clone(&shutdown),
       thread: spawn(async move {
         loop {
-          handler.stats().wait().await;
+          if !(mem::take(&mut post_immediately) && handler.stats().take_fed()) {
+            tokio::select! {
+              _ = handler.stats().wait() => {},
+              _ = shutdown.notified() => break,
+            }
+          }
 
-          {
+          let delay = {
             let stats = handler.stats().stats.read().await;
+            let result = client.post_stats(&stats).await;
+
+            metrics.record(result.is_ok());
 
-            if sender.send(client.post_stats(&stats).await).is_err() {
+            if result.is_ok() {
+              *handler.stats().last_posted.lock().unwrap() = Some(stats.clone());
+            }
+
+            // back off for longer if top.gg asks us to, so the next post doesn't get ratelimited again.
+            let delay = match &result {
+              Err(err) => err
+                .retry_after()
+                .map_or(interval, |retry_after| retry_after.max(interval)),
+              _ => interval,
+            };
+
+            if let (Err(err), Some(on_error)) = (&result, &mut on_error) {
+              on_error(err);
+            }
+
+            if sender.send(result.map(|_| stats.clone())).is_err() {
               break;
             }
+
+            delay
           };
 
-          sleep(interval).await;
+          tokio::select! {
+            _ = sleep(delay) => {},
+            _ = shutdown.notified() => break,
+          }
         }
       }),
       receiver: Some(receiver),
+      last_received: None,
+      stopped: false,
+    }
+  }
+}
+
+impl<H> Autoposter<H>
+where
+  H: Handler,
+{
+  /// Creates an [`Autoposter`] struct as well as immediately starting the thread. The thread will never stop until this struct gets dropped or [shut down][Autoposter::shutdown].
+  ///
+  /// - `client` can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token. (See [`AsClient`])
+  /// - `handler` is a struct that handles the *retrieving stats* part before being sent to the [`Autoposter`]. This datatype is essentially the bridge between an external third-party bot library between this library.
+  ///
+  /// The first post happens as soon as the `handler` feeds its first stats. (See [`AutoposterBuilder::post_immediately`] to post already fed stats right away instead)
+  ///
+  /// # Panics
+  ///
+  /// Panics if the interval argument is shorter than 15 minutes ([`MIN_INTERVAL`]). See [`try_new`][Autoposter::try_new] for a non-panicking alternative.
+  #[inline(always)]
+  pub fn new<C>(client: C, handler: H, interval: Duration) -> Self
+  where
+    C: AsClient,
+  {
+    Self::builder(client, handler, interval).build()
+  }
+
+  /// Creates an [`Autoposter`] struct as well as immediately starting the thread, without checking the interval against [`MIN_INTERVAL`].
+  ///
+  /// **NOTE:** This is only meant for bots that are explicitly authorized by [Top.gg](https://top.gg) to post their stats more often. Posting too often otherwise will get your bot ratelimited. See [`new`][Autoposter::new] for the checked alternative.
+  #[inline(always)]
+  pub fn new_unchecked<C>(client: C, handler: H, interval: Duration) -> Self
+  where
+    C: AsClient,
+  {
+    Self::builder_unchecked(client, handler, interval).build()
+  }
+
+  /// Creates an [`Autoposter`] struct as well as immediately starting the thread, without panicking on an invalid interval. This is useful if the interval is user-configured.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the interval argument is shorter than 15 minutes ([`MIN_INTERVAL`]).
+  pub fn try_new<C>(
+    client: C,
+    handler: H,
+    interval: Duration,
+  ) -> core::result::Result<Self, IntervalTooShort>
+  where
+    C: AsClient,
+  {
+    if interval < MIN_INTERVAL {
+      return Err(IntervalTooShort);
+    }
+
+    Ok(Self::new(client, handler, interval))
+  }
+
+  /// Creates an [`AutoposterBuilder`] for configuring the [`Autoposter`] before starting it.
+  ///
+  /// - `client` can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token. (See [`AsClient`])
+  /// - `handler` is a struct that handles the *retrieving stats* part before being sent to the [`Autoposter`]. This datatype is essentially the bridge between an external third-party bot library between this library.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the interval argument is shorter than 15 minutes (900 seconds).
+  pub fn builder<C>(client: C, handler: H, interval: Duration) -> AutoposterBuilder<H>
+  where
+    C: AsClient,
+  {
+    assert!(
+      interval >= MIN_INTERVAL,
+      "The interval mustn't be shorter than 15 minutes."
+    );
+
+    Self::builder_unchecked(client, handler, interval)
+  }
+
+  /// Creates an [`AutoposterBuilder`] for configuring the [`Autoposter`] before starting it, without checking the interval against [`MIN_INTERVAL`].
+  ///
+  /// **NOTE:** This is only meant for bots that are explicitly authorized by [Top.gg](https://top.gg) to post their stats more often. Posting too often otherwise will get your bot ratelimited. See [`builder`][Autoposter::builder] for the checked alternative.
+  pub fn builder_unchecked<C>(client: C, handler: H, interval: Duration) -> AutoposterBuilder<H>
+  where
+    C: AsClient,
+  {
+    AutoposterBuilder {
+      client: client.as_client(),
+      handler,
+      interval,
+      post_immediately: false,
+      on_error: None,
     }
   }
 
@@ -186,15 +487,80 @@ where
     Arc::clone(&self.handler)
   }
 
-  /// Returns a future that resolves every time the [`Autoposter`] has attempted to post the bot's stats. If you want to use the receiver directly, call [`receiver`][Autoposter::receiver].
+  /// Retrieves when the [`Autoposter`] last attempted to post the bot's stats, or `None` if it hasn't attempted to yet.
+  #[must_use]
+  pub fn last_post_at(&self) -> Option<Instant> {
+    match self.metrics.last_post.load(Ordering::Relaxed) {
+      0 => None,
+      nanos => Some(self.metrics.started_at + Duration::from_nanos(nanos - 1)),
+    }
+  }
+
+  /// Retrieves the amount of times the [`Autoposter`] has successfully posted the bot's stats.
+  #[must_use]
+  #[inline(always)]
+  pub fn success_count(&self) -> u64 {
+    self.metrics.successes.load(Ordering::Relaxed)
+  }
+
+  /// Retrieves the amount of times the [`Autoposter`] has failed to post the bot's stats.
+  #[must_use]
   #[inline(always)]
-  pub async fn recv(&mut self) -> Option<Result<()>> {
-    self.receiver.as_mut().expect("receiver is already taken from the receiver() method. please call recv() directly from the receiver.").recv().await
+  pub fn failure_count(&self) -> u64 {
+    self.metrics.failures.load(Ordering::Relaxed)
+  }
+
+  /// Returns a future that resolves every time the [`Autoposter`] has attempted to post the bot's stats, with the exact [`Stats`] that got posted if it succeeds. If you want to use the receiver directly, call [`receiver`][Autoposter::receiver].
+  ///
+  /// If the autoposter thread stopped unexpectedly, like when its [`Handler`] panics, this resolves to an [`AutoposterStopped`][crate::Error::AutoposterStopped] error once, and to `None` afterwards.
+  #[allow(deprecated)]
+  pub async fn recv(&mut self) -> Option<Result<Stats>> {
+    let result = self.receiver.as_mut().expect("receiver is already taken from the receiver() method. please call recv() directly from the receiver.").recv().await;
+
+    match &result {
+      Some(Ok(stats)) => self.last_received = Some(stats.clone()),
+      None if !mem::replace(&mut self.stopped, true) => return Some(Err(Error::AutoposterStopped)),
+      _ => {}
+    }
+
+    result
+  }
+
+  /// Checks if the autoposter thread is still running. This is `false` if it stopped unexpectedly, like when its [`Handler`] panics, letting you restart it.
+  #[must_use]
+  #[inline(always)]
+  pub fn is_running(&self) -> bool {
+    !self.thread.is_finished()
+  }
+
+  /// Like [`recv`][Autoposter::recv], but skips successful posts whose [`Stats`] are equal to the previously received ones. Failed posts are always returned.
+  ///
+  /// This is useful for only logging when your bot's stats actually change, like when [`SharedStats::always_post`] is enabled.
+  #[allow(deprecated)]
+  pub async fn recv_changed(&mut self) -> Option<Result<Stats>> {
+    loop {
+      let previous = self.last_received.clone();
+
+      match self.recv().await? {
+        Ok(stats) if previous.as_ref() == Some(&stats) => {}
+        result => return Some(result),
+      }
+    }
+  }
+
+  /// Gracefully stops the autoposter thread, letting a post that is still in-flight finish first, and waits until the thread stops.
+  pub async fn shutdown(mut self) {
+    self.shutdown.notify_one();
+
+    let _ = (&mut self.thread).await;
   }
 
   /// Takes the receiver responsible for [`recv`][Autoposter::recv]. Subsequent calls to this function and [`recv`][Autoposter::recv] after this call will panic.
+  ///
+  /// Unlike [`recv`][Autoposter::recv], the receiver simply closes if the autoposter thread stops unexpectedly. (See [`is_running`][Autoposter::is_running])
   #[inline(always)]
-  pub fn receiver(&mut self) -> mpsc::UnboundedReceiver<Result<()>> {
+  #[allow(deprecated)]
+  pub fn receiver(&mut self) -> mpsc::UnboundedReceiver<Result<Stats>> {
     self
       .receiver
       .take()
@@ -214,15 +580,15 @@ impl<H> Deref for Autoposter<H> {
 #[cfg(feature = "serenity")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serenity")))]
 impl Autoposter<Serenity> {
-  /// Creates an [`Autoposter`] struct from an existing built-in [serenity] [`Handler`] as well as immediately starting the thread. The thread will never stop until this struct gets dropped.
+  /// Creates an [`Autoposter`] struct from an existing built-in [serenity] [`Handler`] as well as immediately starting the thread. The thread will never stop until this struct gets dropped or [shut down][Autoposter::shutdown].
   ///
-  /// - `client` can either be a reference to an existing [`Client`][crate::Client] or a [`&str`][std::str] representing a [Top.gg API](https://docs.top.gg) token.
+  /// - `client` can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token. (See [`AsClient`])
   ///
   /// # Panics
   ///
   /// Panics if the interval argument is shorter than 15 minutes (900 seconds).
   #[inline(always)]
-  pub fn serenity<C>(client: &C, interval: Duration) -> Self
+  pub fn serenity<C>(client: C, interval: Duration) -> Self
   where
     C: AsClient,
   {
@@ -233,15 +599,15 @@ impl Autoposter<Serenity> {
 #[cfg(feature = "twilight")]
 #[cfg_attr(docsrs, doc(cfg(feature = "twilight")))]
 impl Autoposter<Twilight> {
-  /// Creates an [`Autoposter`] struct from an existing built-in [twilight](https://twilight.rs) [`Handler`] as well as immediately starting the thread. The thread will never stop until this struct gets dropped.
+  /// Creates an [`Autoposter`] struct from an existing built-in [twilight](https://twilight.rs) [`Handler`] as well as immediately starting the thread. The thread will never stop until this struct gets dropped or [shut down][Autoposter::shutdown].
   ///
-  /// - `client` can either be a reference to an existing [`Client`][crate::Client] or a [`&str`][std::str] representing a [Top.gg API](https://docs.top.gg) token.
+  /// - `client` can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token. (See [`AsClient`])
   ///
   /// # Panics
   ///
   /// Panics if the interval argument is shorter than 15 minutes (900 seconds).
   #[inline(always)]
-  pub fn twilight<C>(client: &C, interval: Duration) -> Self
+  pub fn twilight<C>(client: C, interval: Duration) -> Self
   where
     C: AsClient,
   {
@@ -249,6 +615,46 @@ impl Autoposter<Twilight> {
   }
 }
 
+impl Autoposter<FnHandler> {
+  /// Creates an [`Autoposter`] struct from your own async closure as well as immediately starting the thread. The thread will never stop until this struct gets dropped or [shut down][Autoposter::shutdown].
+  ///
+  /// - `client` can either be an existing [`Client`][crate::Client] (owned, shared through an [`Arc`] or by reference) or a [`&str`][std::str]/[`String`] representing a [Top.gg API](https://docs.top.gg) token. (See [`AsClient`])
+  /// - `f` is called every `interval` to retrieve the bot's latest [`Stats`], or anything that can be converted to it like a server count.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the interval argument is shorter than 15 minutes (900 seconds).
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// use core::time::Duration;
+  /// use topgg::{Autoposter, Client};
+  ///
+  /// # async fn fetch_server_count() -> usize { 0 }
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let client = Client::new(env!("TOPGG_TOKEN").to_string());
+  ///   let autoposter = Autoposter::with_fn(&client, Duration::from_secs(1800), || async {
+  ///     fetch_server_count().await
+  ///   });
+  /// }
+  /// ```
+  #[inline(always)]
+  #[allow(deprecated)]
+  pub fn with_fn<C, F, R, S>(client: C, interval: Duration, f: F) -> Self
+  where
+    C: AsClient,
+    F: Fn() -> R + Send + Sync + 'static,
+    R: Future<Output = S> + Send,
+    S: Into<Stats>,
+  {
+    Self::new(client, FnHandler::new(interval, f), interval)
+  }
+}
+
 impl<H> Drop for Autoposter<H> {
   #[inline(always)]
   fn drop(&mut self) {
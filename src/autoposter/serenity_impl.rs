@@ -17,8 +17,6 @@ cfg_if::cfg_if! {
     struct Cache {
       guilds: HashSet<GuildId>,
     }
-  } else {
-    use std::ops::Add;
   }
 }
 
@@ -54,7 +52,36 @@ macro_rules! serenity_handler {
           }
         }
 
-        /// Handles an entire [serenity] [`FullEvent`] enum. This can be used in [serenity] frameworks.
+        /// Handles an entire [serenity] [`FullEvent`] enum. This can be used in [serenity] frameworks like [poise](https://crates.io/crates/poise), where events are dispatched to a single event handler.
+        ///
+        /// # Examples
+        ///
+        /// Using it in a [poise](https://crates.io/crates/poise) framework:
+        ///
+        /// ```rust,ignore
+        /// # use core::time::Duration;
+        /// # use std::sync::Arc;
+        /// # use topgg::Autoposter;
+        /// #
+        /// # let client = topgg::Client::new(env!("TOPGG_TOKEN").to_string());
+        /// let autoposter = Autoposter::serenity(&client, Duration::from_secs(1800));
+        /// let handler = autoposter.handler();
+        ///
+        /// let framework = poise::Framework::builder()
+        ///   .options(poise::FrameworkOptions {
+        ///     event_handler: move |context, event, _framework, _data| {
+        ///       let handler = Arc::clone(&handler);
+        ///
+        ///       Box::pin(async move {
+        ///         handler.handle(context, event).await;
+        ///
+        ///         Ok(())
+        ///       })
+        ///     },
+        ///     ..Default::default()
+        ///   })
+        ///   .build();
+        /// ```
         pub async fn handle(&$self, $context: &Context, event: &FullEvent) {
           match event {
             $(
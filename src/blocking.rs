@@ -0,0 +1,349 @@
+use crate::{
+  bot::{Bot, GetBots},
+  voter::Voter,
+  Result, Snowflake,
+};
+use core::{borrow::Borrow, future::Future, time::Duration};
+use std::{collections::HashSet, sync::Arc};
+use tokio::runtime::{Builder, Runtime};
+
+#[allow(deprecated)]
+use crate::Stats;
+
+/// A blocking [Top.gg API](https://docs.top.gg) client, mirroring the async [`Client`][crate::Client] for synchronous contexts.
+///
+/// Every method blocks the current thread until the request completes, driving the async [`Client`][crate::Client] on an internal runtime. To configure it, build an async client first and convert it with [`From`], which preserves every option like retries, concurrency limits or caching.
+///
+/// Cloning this struct is cheap, as clones share the same connection pool and runtime.
+///
+/// # Panics
+///
+/// Every method panics if called from within an async runtime, like inside of a `#[tokio::main]` function. Use the async [`Client`][crate::Client] there instead.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::blocking::Client;
+///
+/// let client = Client::new(env!("TOPGG_TOKEN").to_string());
+/// let bot = client.get_bot(264811613708746752).unwrap();
+///
+/// println!("{:?}", bot);
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct Client {
+  inner: crate::Client,
+  runtime: Arc<Runtime>,
+}
+
+impl Client {
+  /// Creates a brand new blocking client instance from a [Top.gg](https://top.gg) token.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the internal runtime can't be created.
+  #[inline(always)]
+  pub fn new(token: String) -> Self {
+    Self::from(crate::Client::new(token))
+  }
+
+  #[inline(always)]
+  fn block_on<F>(&self, future: F) -> F::Output
+  where
+    F: Future,
+  {
+    self.runtime.block_on(future)
+  }
+
+  /// Retrieves the async [`Client`][crate::Client] this client is built on top of.
+  #[inline(always)]
+  pub fn as_async(&self) -> &crate::Client {
+    &self.inner
+  }
+
+  /// Retrieves the underlying [`reqwest::Client`] used by this client. (See [`Client::http`][crate::Client::http])
+  #[must_use]
+  #[inline(always)]
+  pub fn http(&self) -> &reqwest::Client {
+    self.inner.http()
+  }
+
+  /// Blocking version of [`Client::get_bot`][crate::Client::get_bot].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the ID argument is a string but not numeric.
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_bot`][crate::Client::get_bot].
+  #[inline(always)]
+  pub fn get_bot<I>(&self, id: I) -> Result<Bot>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.get_bot(id))
+  }
+
+  /// Blocking version of [`Client::get_self`][crate::Client::get_self].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_self`][crate::Client::get_self].
+  #[inline(always)]
+  pub fn get_self(&self) -> Result<Bot> {
+    self.block_on(self.inner.get_self())
+  }
+
+  /// Blocking version of [`Client::try_get_bot`][crate::Client::try_get_bot].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::try_get_bot`][crate::Client::try_get_bot].
+  #[inline(always)]
+  pub fn try_get_bot<I>(&self, id: I) -> Result<Option<Bot>>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.try_get_bot(id))
+  }
+
+  /// Blocking version of [`Client::get_bots_by_ids`][crate::Client::get_bots_by_ids].
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the IDs is a string but not numeric.
+  #[inline(always)]
+  pub fn get_bots_by_ids<I, T>(&self, ids: I, concurrency: usize) -> Vec<Result<Bot>>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    self.block_on(self.inner.get_bots_by_ids(ids, concurrency))
+  }
+
+  /// Blocking version of [`Client::get_bots`][crate::Client::get_bots], configuring the query through the `query` closure.
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_bots`][crate::Client::get_bots].
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// use topgg::blocking::Client;
+  ///
+  /// let client = Client::new(env!("TOPGG_TOKEN").to_string());
+  /// let bots = client.get_bots(|query| query.limit(250).sort_by_monthly_votes());
+  /// ```
+  #[inline(always)]
+  pub fn get_bots<F>(&self, query: F) -> Result<Vec<Bot>>
+  where
+    F: for<'a> FnOnce(GetBots<'a>) -> GetBots<'a>,
+  {
+    self.block_on(async { query(self.inner.get_bots()).await })
+  }
+
+  /// Blocking version of [`Client::get_stats`][crate::Client::get_stats].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_stats`][crate::Client::get_stats].
+  #[inline(always)]
+  #[allow(deprecated)]
+  pub fn get_stats(&self) -> Result<Stats> {
+    self.block_on(self.inner.get_stats())
+  }
+
+  /// Blocking version of [`Client::get_bot_stats`][crate::Client::get_bot_stats].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the ID argument is a string but not numeric.
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_bot_stats`][crate::Client::get_bot_stats].
+  #[inline(always)]
+  pub fn get_bot_stats<I>(&self, id: I) -> Result<Option<usize>>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.get_bot_stats(id))
+  }
+
+  /// Blocking version of [`Client::validate_token`][crate::Client::validate_token].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::validate_token`][crate::Client::validate_token].
+  #[inline(always)]
+  pub fn validate_token(&self) -> Result<bool> {
+    self.block_on(self.inner.validate_token())
+  }
+
+  /// Blocking version of [`Client::ping`][crate::Client::ping].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::ping`][crate::Client::ping].
+  #[inline(always)]
+  pub fn ping(&self) -> Result<Duration> {
+    self.block_on(self.inner.ping())
+  }
+
+  /// Blocking version of [`Client::post_stats`][crate::Client::post_stats].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::post_stats`][crate::Client::post_stats].
+  #[inline(always)]
+  #[allow(deprecated)]
+  pub fn post_stats<S>(&self, new_stats: S) -> Result<()>
+  where
+    S: Borrow<Stats>,
+  {
+    self.block_on(self.inner.post_stats(new_stats))
+  }
+
+  /// Blocking version of [`Client::get_voters`][crate::Client::get_voters].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_voters`][crate::Client::get_voters].
+  #[inline(always)]
+  pub fn get_voters(&self) -> Result<Vec<Voter>> {
+    self.block_on(self.inner.get_voters())
+  }
+
+  /// Blocking version of [`Client::get_voters_page`][crate::Client::get_voters_page].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_voters_page`][crate::Client::get_voters_page].
+  #[inline(always)]
+  pub fn get_voters_page(&self, page: u16) -> Result<Vec<Voter>> {
+    self.block_on(self.inner.get_voters_page(page))
+  }
+
+  /// Blocking version of [`Client::get_vote_count`][crate::Client::get_vote_count].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the user ID argument is a string and it's not a valid ID (expected things like `"123456789"`).
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::get_vote_count`][crate::Client::get_vote_count].
+  #[inline(always)]
+  pub fn get_vote_count<I>(&self, user_id: I) -> Result<u32>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.get_vote_count(user_id))
+  }
+
+  /// Blocking version of [`Client::has_voted`][crate::Client::has_voted].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the user ID argument is a string and it's not a valid ID (expected things like `"123456789"`).
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::has_voted`][crate::Client::has_voted].
+  #[inline(always)]
+  pub fn has_voted<I>(&self, user_id: I) -> Result<bool>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.has_voted(user_id))
+  }
+
+  /// Blocking version of [`Client::try_has_voted`][crate::Client::try_has_voted].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::try_has_voted`][crate::Client::try_has_voted].
+  #[inline(always)]
+  pub fn try_has_voted<I>(&self, user_id: I) -> Result<bool>
+  where
+    I: Snowflake,
+  {
+    self.block_on(self.inner.try_has_voted(user_id))
+  }
+
+  /// Blocking version of [`Client::has_voted_many`][crate::Client::has_voted_many].
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the user IDs is a string and it's not a valid ID (expected things like `"123456789"`).
+  #[inline(always)]
+  pub fn has_voted_many<I, T>(&self, user_ids: I, concurrency: usize) -> Vec<(u64, Result<bool>)>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    self.block_on(self.inner.has_voted_many(user_ids, concurrency))
+  }
+
+  /// Blocking version of [`Client::voters_among`][crate::Client::voters_among].
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the user IDs is a string and it's not a valid ID (expected things like `"123456789"`).
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::voters_among`][crate::Client::voters_among].
+  #[inline(always)]
+  pub fn voters_among<I, T>(&self, user_ids: I, concurrency: usize) -> Result<HashSet<u64>>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    self.block_on(self.inner.voters_among(user_ids, concurrency))
+  }
+
+  /// Blocking version of [`Client::is_weekend`][crate::Client::is_weekend].
+  ///
+  /// # Errors
+  ///
+  /// See [`Client::is_weekend`][crate::Client::is_weekend].
+  #[inline(always)]
+  pub fn is_weekend(&self) -> Result<bool> {
+    self.block_on(self.inner.is_weekend())
+  }
+
+  /// Clears every bot cached by [`get_bot`][Client::get_bot]. (See [`Client::clear_cache`][crate::Client::clear_cache])
+  #[inline(always)]
+  #[cfg(feature = "cache")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+  pub fn clear_cache(&self) {
+    self.inner.clear_cache();
+  }
+}
+
+impl From<crate::Client> for Client {
+  /// Wraps an existing async [`Client`][crate::Client], sharing its configuration and connection pool.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the internal runtime can't be created.
+  fn from(inner: crate::Client) -> Self {
+    let runtime = Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .expect("failed to create the blocking client's runtime");
+
+    Self {
+      inner,
+      runtime: Arc::new(runtime),
+    }
+  }
+}
@@ -1,12 +1,32 @@
-use crate::{snowflake, util, Client};
+use crate::{snowflake, util, Client, Snowflake};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer, Serialize};
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::{de::IgnoredAny, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
   cmp::min,
+  collections::HashMap,
+  fmt,
   future::{Future, IntoFuture},
+  hash::{Hash, Hasher},
   pin::Pin,
 };
 
+/// Retrieves the URL for voting a bot on [Top.gg](https://top.gg) from its Discord ID.
+///
+/// # Panics
+///
+/// Panics if the ID argument is a string but not numeric.
+#[must_use]
+#[inline(always)]
+pub fn vote_url<I>(bot_id: I) -> String
+where
+  I: Snowflake,
+{
+  format!("https://top.gg/bot/{}/vote", bot_id.as_snowflake())
+}
+
+const SUPPORT_SERVER_PREFIX: &str = "https://discord.com/invite/";
+
 #[inline(always)]
 pub(crate) fn deserialize_support_server<'de, D>(
   deserializer: D,
@@ -15,37 +35,61 @@ where
   D: Deserializer<'de>,
 {
   util::deserialize_optional_string(deserializer)
-    .map(|inner| inner.map(|support| format!("https://discord.com/invite/{support}")))
+    .map(|inner| inner.map(|support| format!("{SUPPORT_SERVER_PREFIX}{support}")))
+}
+
+#[inline(always)]
+fn serialize_support_server<S>(support: &Option<String>, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  support
+    .as_deref()
+    .map(|support| support.trim_start_matches(SUPPORT_SERVER_PREFIX))
+    .serialize(serializer)
 }
 
 // TODO: remove these utility deprecation helpers soon
 
 #[inline(always)]
-fn deserialize_discriminator<'de, D>(_deserializer: D) -> Result<String, D::Error>
+fn deserialize_discriminator<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
   D: Deserializer<'de>,
 {
-  Ok(String::from('0'))
+  IgnoredAny::deserialize(deserializer).map(|_| default_discriminator())
+}
+
+#[inline(always)]
+fn default_discriminator() -> String {
+  String::from('0')
 }
 
 util::debug_struct! {
   /// A struct representing a bot listed on [Top.gg](https://top.gg).
   #[must_use]
-  #[derive(Clone, Deserialize)]
+  #[derive(Clone, Serialize, Deserialize)]
   Bot {
     public {
       /// The application ID of this bot.
-      #[serde(rename = "clientid", deserialize_with = "snowflake::deserialize")]
+      #[serde(
+        rename = "clientid",
+        serialize_with = "snowflake::serialize",
+        deserialize_with = "snowflake::deserialize"
+      )]
       id: u64,
 
       /// The Top.gg user ID of this bot.
-      #[serde(rename = "id", deserialize_with = "snowflake::deserialize")]
+      #[serde(
+        rename = "id",
+        serialize_with = "snowflake::serialize",
+        deserialize_with = "snowflake::deserialize"
+      )]
       topgg_id: u64,
 
       /// The username of this bot.
       username: String,
 
-      #[serde(deserialize_with = "deserialize_discriminator")]
+      #[serde(default = "default_discriminator", deserialize_with = "deserialize_discriminator")]
       #[deprecated(since = "1.4.3", note = "No longer supported by Top.gg API v0. At the moment, this will always be '0'.")]
       discriminator: String,
 
@@ -77,10 +121,13 @@ util::debug_struct! {
       github: Option<String>,
 
       /// A list of IDs of this bot's owners. The main owner is the first ID in the array.
-      #[serde(deserialize_with = "snowflake::deserialize_vec")]
+      #[serde(
+        serialize_with = "snowflake::serialize_vec",
+        deserialize_with = "snowflake::deserialize_vec"
+      )]
       owners: Vec<u64>,
 
-      #[serde(deserialize_with = "util::deserialize_immediate_default")]
+      #[serde(default, deserialize_with = "util::deserialize_immediate_default")]
       #[deprecated(since = "1.4.3", note = "No longer supported by Top.gg API v0. At the moment, this will always be an empty vector.")]
       guilds: Vec<u64>,
 
@@ -92,15 +139,19 @@ util::debug_struct! {
       )]
       banner_url: Option<String>,
 
-      /// The date when this bot was approved on [Top.gg](https://top.gg).
-      #[serde(rename = "date")]
-      approved_at: DateTime<Utc>,
+      /// The date when this bot was approved on [Top.gg](https://top.gg). This is `None` if [Top.gg](https://top.gg) returns a missing or malformed date, which happens for some legacy listings.
+      #[serde(
+        default,
+        rename = "date",
+        deserialize_with = "util::deserialize_lenient"
+      )]
+      approved_at: Option<DateTime<Utc>>,
 
-      #[serde(deserialize_with = "util::deserialize_immediate_default")]
+      #[serde(default, deserialize_with = "util::deserialize_immediate_default")]
       #[deprecated(since = "1.4.3", note = "No longer supported by Top.gg API v0. At the moment, this will always be false.")]
       is_certified: bool,
 
-      #[serde(deserialize_with = "util::deserialize_immediate_default")]
+      #[serde(default, deserialize_with = "util::deserialize_immediate_default")]
       #[deprecated(since = "1.4.3", note = "No longer supported by Top.gg API v0. At the moment, this will always be an empty vector.")]
       shards: Vec<usize>,
 
@@ -113,8 +164,21 @@ util::debug_struct! {
       monthly_votes: usize,
 
       /// The support server invite URL of this bot.
-      #[serde(default, deserialize_with = "deserialize_support_server")]
+      #[serde(
+        default,
+        serialize_with = "serialize_support_server",
+        deserialize_with = "deserialize_support_server"
+      )]
       support: Option<String>,
+
+      /// Fields returned by the [Top.gg API](https://docs.top.gg) that are not modeled by this struct yet, letting you access newly added fields before this SDK catches up.
+      #[serde(flatten)]
+      extra: HashMap<String, serde_json::Value>,
+    }
+
+    protected {
+      #[serde(skip)]
+      source: BotSource,
     }
 
     private {
@@ -129,11 +193,18 @@ util::debug_struct! {
     }
 
     getters(self) {
+      /// Retrieves where this bot came from. If it's [`BotSource::Search`], missing optional fields may not actually be missing on [Top.gg](https://top.gg).
+      #[must_use]
+      #[inline(always)]
+      source: BotSource => {
+        self.source
+      }
+
       /// Retrieves the creation date of this bot.
       #[must_use]
       #[inline(always)]
       created_at: DateTime<Utc> => {
-        util::get_creation_date(self.id)
+        snowflake::snowflake_timestamp(self.id)
       }
 
       /// Retrieves the avatar URL of this bot.
@@ -142,7 +213,7 @@ util::debug_struct! {
       #[must_use]
       #[inline(always)]
       avatar: String => {
-        util::get_avatar(&self.avatar, self.id)
+        util::get_avatar(self.avatar.as_deref(), self.id)
       }
 
       /// The invite URL of this bot.
@@ -162,6 +233,13 @@ util::debug_struct! {
         0
       }
 
+      /// Retrieves the URL of this bot's large [Top.gg](https://top.gg) SVG widget. (See [`Bot::small_widget_url`] for the smaller variants)
+      #[must_use]
+      #[inline(always)]
+      widget_url: String => {
+        format!("https://top.gg/api/widget/{}.svg", self.id)
+      }
+
       /// Retrieves the URL of this bot's [Top.gg](https://top.gg) page.
       #[must_use]
       #[inline(always)]
@@ -175,13 +253,229 @@ util::debug_struct! {
   }
 }
 
+/// Where a [`Bot`] came from, which determines the fields that are reliably present. (See [`Bot::source`])
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BotSource {
+  /// Fetched directly from its ID with [`get_bot`][crate::Client::get_bot], having every field populated. This is also the case for bots deserialized by yourself.
+  #[default]
+  Fetched,
+
+  /// Returned from a search query with [`get_bots`][crate::Client::get_bots]. [Top.gg](https://top.gg) may omit some fields like the invite URL or the vanity URL from search results, making them fall back to their defaults.
+  Search,
+}
+
+/// A small [Top.gg](https://top.gg) SVG widget variant. (See [`Bot::small_widget_url`])
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SmallWidget {
+  /// Displays the bot's owner.
+  Owner,
+
+  /// Displays the bot's online status.
+  Status,
+
+  /// Displays the bot's total votes.
+  Upvotes,
+
+  /// Displays the bot's server count.
+  Servers,
+
+  /// Displays the bot's library.
+  Library,
+}
+
+impl SmallWidget {
+  #[inline(always)]
+  const fn path(self) -> &'static str {
+    match self {
+      Self::Owner => "owner",
+      Self::Status => "status",
+      Self::Upvotes => "upvotes",
+      Self::Servers => "servers",
+      Self::Library => "lib",
+    }
+  }
+}
+
+// the source is left out, as the same bot can be fetched from different endpoints.
+#[allow(deprecated)]
+impl PartialEq for Bot {
+  fn eq(&self, other: &Self) -> bool {
+    let Self {
+      id,
+      topgg_id,
+      username,
+      discriminator,
+      prefix,
+      short_description,
+      long_description,
+      tags,
+      website,
+      github,
+      owners,
+      guilds,
+      banner_url,
+      approved_at,
+      is_certified,
+      shards,
+      votes,
+      monthly_votes,
+      support,
+      extra,
+      source: _,
+      avatar,
+      invite,
+      vanity,
+    } = self;
+
+    *id == other.id
+      && *topgg_id == other.topgg_id
+      && *username == other.username
+      && *discriminator == other.discriminator
+      && *prefix == other.prefix
+      && *short_description == other.short_description
+      && *long_description == other.long_description
+      && *tags == other.tags
+      && *website == other.website
+      && *github == other.github
+      && *owners == other.owners
+      && *guilds == other.guilds
+      && *banner_url == other.banner_url
+      && *approved_at == other.approved_at
+      && *is_certified == other.is_certified
+      && *shards == other.shards
+      && *votes == other.votes
+      && *monthly_votes == other.monthly_votes
+      && *support == other.support
+      && *extra == other.extra
+      && *avatar == other.avatar
+      && *invite == other.invite
+      && *vanity == other.vanity
+  }
+}
+
+impl Eq for Bot {}
+
+// hashed by ID only, which is consistent with equality as equal bots always share the same ID.
+impl Hash for Bot {
+  #[inline(always)]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.id.hash(state);
+  }
+}
+
+impl Bot {
+  /// Retrieves the URL of one of this bot's small [Top.gg](https://top.gg) SVG widgets. (See [`widget_url`][Bot::widget_url] for the large one)
+  #[must_use]
+  #[inline(always)]
+  pub fn small_widget_url(&self, widget: SmallWidget) -> String {
+    format!(
+      "https://top.gg/api/widget/{}/{}.svg",
+      widget.path(),
+      self.id
+    )
+  }
+
+  /// Retrieves the URL for voting this bot on [Top.gg](https://top.gg), using its vanity URL if it has one. (See [`vote_url`] for voting any bot from its ID)
+  #[must_use]
+  #[inline(always)]
+  pub fn vote_url(&self) -> String {
+    format!("{}/vote", self.url())
+  }
+
+  /// Retrieves the amount of upvotes this bot has this month. This is the same as [`monthly_votes`][Bot::monthly_votes].
+  #[must_use]
+  #[inline(always)]
+  pub const fn vote_count_this_month(&self) -> usize {
+    self.monthly_votes
+  }
+
+  /// Retrieves the raw Discord avatar hash of this bot, or `None` if it uses a default avatar.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar_hash(&self) -> Option<&str> {
+    self.avatar.as_deref()
+  }
+
+  /// Checks if this bot's avatar is animated, meaning its [avatar URL][Bot::avatar] is a GIF instead of a PNG.
+  #[must_use]
+  #[inline(always)]
+  pub fn is_animated_avatar(&self) -> bool {
+    self.avatar.as_deref().is_some_and(util::is_animated_avatar)
+  }
+
+  /// Retrieves the avatar URL of this bot with a specific size.
+  ///
+  /// Discord only supports sizes that are powers of two from 16 to 4096, so `size` is rounded to the nearest supported size. Its format will either be PNG or GIF if animated.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar_with_size(&self, size: u16) -> String {
+    util::get_avatar_with_size(self.avatar.as_deref(), self.id, size)
+  }
+}
+
 #[derive(Deserialize)]
-pub(crate) struct Bots {
-  pub(crate) results: Vec<Bot>,
+pub(crate) struct Bots<T = Bot> {
+  pub(crate) results: Vec<T>,
+  #[serde(default)]
+  pub(crate) total: usize,
 }
 
+/// The fields requested from the [Top.gg API](https://docs.top.gg) for a [`BotSummary`].
+const SUMMARY_FIELDS: &str = "clientid,id,username,avatar";
+
 util::debug_struct! {
-  #[derive(Clone, Serialize, Deserialize)]
+  /// A lightweight struct representing a Discord bot listed on [Top.gg](https://top.gg), with only the fields commonly needed for things like autocompletion. (See [`GetBots::summaries`])
+  #[must_use]
+  #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+  BotSummary {
+    public {
+      /// The application ID of this bot.
+      #[serde(
+        rename = "clientid",
+        serialize_with = "snowflake::serialize",
+        deserialize_with = "snowflake::deserialize"
+      )]
+      id: u64,
+
+      /// The Top.gg user ID of this bot.
+      #[serde(
+        rename = "id",
+        serialize_with = "snowflake::serialize",
+        deserialize_with = "snowflake::deserialize"
+      )]
+      topgg_id: u64,
+
+      /// The username of this bot.
+      username: String,
+    }
+
+    private {
+      #[serde(default, deserialize_with = "util::deserialize_optional_string")]
+      avatar: Option<String>,
+    }
+
+    getters(self) {
+      /// Retrieves the avatar URL of this bot.
+      ///
+      /// Its format will either be PNG or GIF if animated.
+      #[must_use]
+      #[inline(always)]
+      avatar: String => {
+        util::get_avatar(self.avatar.as_deref(), self.id)
+      }
+    }
+  }
+}
+
+impl Hash for BotSummary {
+  #[inline(always)]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.id.hash(state);
+  }
+}
+
+util::debug_struct! {
+  #[derive(Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
   #[deprecated(since = "1.4.3", note = "No longer has a use by Top.gg API v0. Soon, all you need is just your bot's server count (usize).")]
   Stats {
     protected {
@@ -207,7 +501,14 @@ util::debug_struct! {
   }
 }
 
+#[allow(deprecated)]
 impl Stats {
+  /// Sets this [`Stats`]' server count. If `None`, it will be omitted when posted.
+  #[inline(always)]
+  pub fn set_server_count(&mut self, server_count: Option<usize>) {
+    self.server_count = server_count;
+  }
+
   /// Creates a [`Stats`] struct from the cache of a serenity [`Context`][serenity::client::Context].
   #[inline(always)]
   #[cfg(feature = "serenity-cached")]
@@ -250,6 +551,7 @@ impl Stats {
   }
 }
 
+#[allow(deprecated)]
 impl From<usize> for Stats {
   #[inline(always)]
   fn from(server_count: usize) -> Self {
@@ -257,18 +559,57 @@ impl From<usize> for Stats {
   }
 }
 
+#[allow(deprecated)]
+impl From<Option<usize>> for Stats {
+  #[inline(always)]
+  fn from(server_count: Option<usize>) -> Self {
+    Self { server_count }
+  }
+}
+
 #[derive(Deserialize)]
 pub(crate) struct IsWeekend {
   pub(crate) is_weekend: bool,
 }
 
+/// The maximum amount of bots that can be queried at once. (See [`GetBots::limit`])
+pub const MAX_LIMIT: u16 = 500;
+
+/// The maximum amount of bots that can be skipped during a query. (See [`GetBots::skip`])
+pub const MAX_SKIP: u16 = 499;
+
+/// The amount of bots queried at once if not specified. (See [`GetBots::limit`])
+pub const DEFAULT_LIMIT: u16 = 50;
+
+// filtered client-side, as the top.gg API only supports exact vote counts.
+#[derive(Clone, Copy, Default)]
+struct VotesRange {
+  min: Option<usize>,
+  max: Option<usize>,
+}
+
+impl VotesRange {
+  #[inline(always)]
+  fn contains(&self, bot: &Bot) -> bool {
+    self.min.is_none_or(|min| bot.votes >= min) && self.max.is_none_or(|max| bot.votes <= max)
+  }
+}
+
 /// A struct for configuring the query in [`get_bots`][crate::Client::get_bots] before being sent to the [Top.gg API](https://docs.top.gg) by `await`ing it.
+///
+/// # Cancellation safety
+///
+/// Every future created from this query, including the one from `await`ing it, is cancellation safe. Each of them owns its query string, and dropping one before it completes (like in a losing `tokio::select!` branch) simply aborts its in-flight request, releasing its [concurrency][crate::ClientBuilder::max_concurrent] slot without affecting the [`Client`] or other requests.
 #[must_use]
+#[derive(Clone)]
 pub struct GetBots<'a> {
   client: &'a Client,
-  query: String,
+  limit: Option<u16>,
+  skip: Option<u16>,
   search: String,
   sort: Option<&'static str>,
+  ascending: bool,
+  votes_range: VotesRange,
 }
 
 macro_rules! get_bots_method {
@@ -302,12 +643,21 @@ impl<'a> GetBots<'a> {
   pub(crate) fn new(client: &'a Client) -> Self {
     Self {
       client,
-      query: String::from('?'),
+      limit: None,
+      skip: None,
       search: String::new(),
       sort: None,
+      ascending: false,
+      votes_range: VotesRange::default(),
     }
   }
 
+  /// Clears every filter, sort and pagination option set so far, as if this query was freshly created. Use [`clone`][Clone::clone] instead if you want to branch off this query without losing it.
+  #[inline(always)]
+  pub fn reset(self) -> Self {
+    Self::new(self.client)
+  }
+
   get_bots_sort! {
     /// Sorts results based on each bot's ID.
     sort_by_id: id,
@@ -319,20 +669,52 @@ impl<'a> GetBots<'a> {
     sort_by_monthly_votes: monthlyPoints,
   }
 
-  get_bots_method! {
-    /// Sets the maximum amount of bots to be queried. This cannot be more than 500.
-    limit: u16 = query("limit={}&", min(limit, 500));
+  /// Sorts results in ascending order instead. This only has an effect if a sort is specified.
+  pub fn ascending(mut self) -> Self {
+    self.ascending = true;
+    self
+  }
+
+  /// Sorts results in descending order. This is the default and only has an effect if a sort is specified.
+  pub fn descending(mut self) -> Self {
+    self.ascending = false;
+    self
+  }
+
+  /// Sets the maximum amount of bots to be queried. This must be between 1 and 500 ([`MAX_LIMIT`]), so values outside of that range are clamped to it. (See [`effective_limit`][GetBots::effective_limit])
+  pub fn limit(mut self, limit: u16) -> Self {
+    self.limit.replace(limit.clamp(1, MAX_LIMIT));
+    self
+  }
+
+  /// Sets the amount of bots to be skipped during the query. This cannot be more than 499 ([`MAX_SKIP`]), so larger values are clamped to it. (See [`effective_skip`][GetBots::effective_skip])
+  pub fn skip(mut self, skip: u16) -> Self {
+    self.skip.replace(min(skip, MAX_SKIP));
+    self
+  }
 
-    /// Sets the amount of bots to be skipped during the query. This cannot be more than 499.
-    skip: u16 = query("offset={}&", min(skip, 499));
+  /// Retrieves the maximum amount of bots that will actually be queried, after clamping. This is [`DEFAULT_LIMIT`] if not specified.
+  #[must_use]
+  #[inline(always)]
+  pub fn effective_limit(&self) -> u16 {
+    self.limit.unwrap_or(DEFAULT_LIMIT)
+  }
+
+  /// Retrieves the amount of bots that will actually be skipped during the query, after clamping. This is zero if not specified.
+  #[must_use]
+  #[inline(always)]
+  pub fn effective_skip(&self) -> u16 {
+    self.skip.unwrap_or(0)
+  }
 
+  get_bots_method! {
     /// Queries only Discord bots that has this username.
     username: &str = search("username%3A%20{}%20", urlencoding::encode(username));
 
     /// Queries only Discord bots that has this prefix.
     prefix: &str = search("prefix%3A%20{}%20", urlencoding::encode(prefix));
 
-    /// Queries only Discord bots that has this vote count.
+    /// Queries only Discord bots that has exactly this vote count. See [`min_votes`][GetBots::min_votes] and [`max_votes`][GetBots::max_votes] for querying a range of vote counts instead.
     votes: usize = search("points%3A%20{votes}%20");
 
     /// Queries only Discord bots that has this monthly vote count.
@@ -340,18 +722,42 @@ impl<'a> GetBots<'a> {
 
     /// Queries only Discord bots that has this [Top.gg](https://top.gg) vanity URL.
     vanity: &str = search("vanity%3A%20{}%20", urlencoding::encode(vanity));
+
+    /// Queries only Discord bots that has this tag. Calling this multiple times queries only Discord bots that has all of the specified tags.
+    tag: &str = search("tags%3A%20{}%20", urlencoding::encode(tag));
   }
-}
 
-impl<'a> IntoFuture for GetBots<'a> {
-  type Output = crate::Result<Vec<Bot>>;
-  type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+  /// Keeps only Discord bots that has at least this vote count.
+  ///
+  /// **NOTE:** The [Top.gg API](https://docs.top.gg) only supports querying exact vote counts, so this is filtered from the fetched results instead. This means that a query can return less bots than its [limit][GetBots::limit], even if more bots match it. Sorting by vote count with [`sort_by_monthly_votes`][GetBots::sort_by_monthly_votes] or using [`stream`][GetBots::stream] helps going through more bots.
+  pub fn min_votes(mut self, min_votes: usize) -> Self {
+    self.votes_range.min.replace(min_votes);
+    self
+  }
 
-  fn into_future(self) -> Self::IntoFuture {
-    let mut query = self.query;
+  /// Keeps only Discord bots that has at most this vote count.
+  ///
+  /// **NOTE:** Like [`min_votes`][GetBots::min_votes], this is filtered from the fetched results instead of being sent to the [Top.gg API](https://docs.top.gg).
+  pub fn max_votes(mut self, max_votes: usize) -> Self {
+    self.votes_range.max.replace(max_votes);
+    self
+  }
+
+  fn query(&self, skip: Option<u16>) -> String {
+    let mut query = String::from('?');
+
+    if let Some(limit) = self.limit {
+      query.push_str(&format!("limit={limit}&"));
+    }
+
+    if let Some(skip) = skip {
+      query.push_str(&format!("offset={skip}&"));
+    }
 
     if let Some(sort) = self.sort {
-      query.push_str(&format!("sort={sort}&"));
+      let direction = if self.ascending { "-" } else { "" };
+
+      query.push_str(&format!("sort={direction}{sort}&"));
     }
 
     if !self.search.is_empty() {
@@ -360,6 +766,218 @@ impl<'a> IntoFuture for GetBots<'a> {
       query.pop();
     }
 
-    Box::pin(self.client.get_bots_inner(query))
+    query
+  }
+
+  /// Turns this query into a [`Stream`] of bots, transparently fetching successive pages by bumping the amount of skipped bots until the results run dry.
+  ///
+  /// Since the [Top.gg API](https://docs.top.gg) doesn't allow skipping more than 499 bots, the stream also ends once that limit is reached. The stream ends right after yielding an error.
+  pub fn stream(self) -> impl Stream<Item = crate::Result<Bot>> + Send + 'static {
+    let client = self.client.clone();
+    let page_size = self.effective_limit() as usize;
+    let votes_range = self.votes_range;
+
+    let queries: Vec<_> = (self.effective_skip()..=MAX_SKIP)
+      .step_by(page_size)
+      .map(|skip| self.query(Some(skip)))
+      .collect();
+
+    stream::try_unfold(Some(queries.into_iter()), move |queries| {
+      let client = client.clone();
+
+      async move {
+        let Some(mut queries) = queries else {
+          return Ok(None);
+        };
+
+        let Some(query) = queries.next() else {
+          return Ok(None);
+        };
+
+        let bots = client.get_bots_inner(query).await?.results;
+
+        if bots.is_empty() {
+          return Ok(None);
+        }
+
+        let next = (bots.len() >= page_size).then_some(queries);
+
+        let bots = bots
+          .into_iter()
+          .filter(move |bot| votes_range.contains(bot));
+
+        Ok(Some((stream::iter(bots.map(Ok)), next)))
+      }
+    })
+    .try_flatten()
+  }
+
+  /// Sends this query like `await`ing it would, but also returns the total amount of bots matching it, regardless of the limit.
+  ///
+  /// The total is reported by the [Top.gg API](https://docs.top.gg), so it ignores [`min_votes`][GetBots::min_votes] and [`max_votes`][GetBots::max_votes].
+  ///
+  /// # Errors
+  ///
+  /// See [`get_bots`][crate::Client::get_bots].
+  pub async fn with_total(self) -> crate::Result<(Vec<Bot>, usize)> {
+    self
+      .client
+      .get_bots_inner(self.query(self.skip))
+      .await
+      .map(|bots| (self.filter(bots.results), bots.total))
+  }
+
+  /// Sends this query like `await`ing it would, but only requests the fields needed for a [`BotSummary`] from the [Top.gg API](https://docs.top.gg), reducing the response size.
+  ///
+  /// Since a [`BotSummary`] doesn't have a vote count, [`min_votes`][GetBots::min_votes] and [`max_votes`][GetBots::max_votes] are ignored.
+  ///
+  /// # Errors
+  ///
+  /// See [`get_bots`][crate::Client::get_bots].
+  pub async fn summaries(self) -> crate::Result<Vec<BotSummary>> {
+    let mut query = self.query(self.skip);
+
+    query.push(if query.is_empty() { '?' } else { '&' });
+    query.push_str("fields=");
+    query.push_str(SUMMARY_FIELDS);
+
+    self
+      .client
+      .get_bot_summaries_inner(query)
+      .await
+      .map(|bots| bots.results)
+  }
+
+  /// Sends this query like `await`ing it would, but returns a [`BotsPage`] that can fetch the following pages of results by itself.
+  ///
+  /// # Errors
+  ///
+  /// See [`get_bots`][crate::Client::get_bots].
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// # async fn example(client: topgg::Client) -> topgg::Result<()> {
+  /// let mut page = client.get_bots().username("shiro").page().await?;
+  ///
+  /// loop {
+  ///   for bot in page.bots() {
+  ///     println!("{:?}", bot);
+  ///   }
+  ///
+  ///   match page.next().await? {
+  ///     Some(next) => page = next,
+  ///     None => break,
+  ///   }
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn page(self) -> crate::Result<BotsPage<'a>> {
+    let bots = self.client.get_bots_inner(self.query(self.skip)).await?;
+    let has_more = bots.results.len() >= self.effective_limit() as usize
+      && self.next_skip() <= MAX_SKIP as usize;
+
+    Ok(BotsPage {
+      bots: self.filter(bots.results),
+      total: bots.total,
+      has_more,
+      query: self,
+    })
+  }
+
+  #[inline(always)]
+  fn next_skip(&self) -> usize {
+    self.effective_skip() as usize + self.effective_limit() as usize
+  }
+
+  #[inline(always)]
+  fn filter(&self, mut bots: Vec<Bot>) -> Vec<Bot> {
+    bots.retain(|bot| self.votes_range.contains(bot));
+    bots
+  }
+}
+
+/// A page of bots fetched with [`GetBots::page`], which can fetch the page after it without having to bump the amount of skipped bots manually.
+#[must_use]
+#[derive(Clone)]
+pub struct BotsPage<'a> {
+  bots: Vec<Bot>,
+  total: usize,
+  has_more: bool,
+  query: GetBots<'a>,
+}
+
+impl<'a> BotsPage<'a> {
+  /// Retrieves the bots in this page.
+  #[must_use = "retrieving the bots has no effect if they are unused"]
+  #[inline(always)]
+  pub fn bots(&self) -> &[Bot] {
+    &self.bots
+  }
+
+  /// Consumes this page and retrieves the bots in it.
+  #[must_use]
+  #[inline(always)]
+  pub fn into_bots(self) -> Vec<Bot> {
+    self.bots
+  }
+
+  /// Retrieves the total amount of bots matching the query, regardless of the limit. (See [`GetBots::with_total`])
+  #[must_use]
+  #[inline(always)]
+  pub const fn total(&self) -> usize {
+    self.total
+  }
+
+  /// Fetches the page after this one, or `None` if this is the last one.
+  ///
+  /// Since the [Top.gg API](https://docs.top.gg) doesn't allow skipping more than 499 bots ([`MAX_SKIP`]), pages after that are never fetched.
+  ///
+  /// # Errors
+  ///
+  /// See [`get_bots`][crate::Client::get_bots].
+  pub async fn next(&self) -> crate::Result<Option<BotsPage<'a>>> {
+    if !self.has_more {
+      return Ok(None);
+    }
+
+    self
+      .query
+      .clone()
+      .skip(self.query.next_skip() as _)
+      .page()
+      .await
+      .map(Some)
+  }
+}
+
+impl fmt::Debug for BotsPage<'_> {
+  fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    fmt
+      .debug_struct("BotsPage")
+      .field("bots", &self.bots)
+      .field("total", &self.total)
+      .finish()
+  }
+}
+
+// cancellation safe, as the boxed future owns its query string and the client doesn't hold any state across await points that could be left inconsistent by dropping it.
+impl<'a> IntoFuture for GetBots<'a> {
+  type Output = crate::Result<Vec<Bot>>;
+  type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+
+  fn into_future(self) -> Self::IntoFuture {
+    let query = self.query(self.skip);
+
+    Box::pin(async move {
+      self
+        .client
+        .get_bots_inner(query)
+        .await
+        .map(|bots| self.filter(bots.results))
+    })
   }
 }
@@ -1,99 +1,311 @@
 use crate::{
-  bot::{Bot, Bots, GetBots, IsWeekend, Stats},
+  bot::{Bot, BotSource, BotSummary, Bots, GetBots, IsWeekend},
   util,
   voter::{Voted, Voter},
   Error, Result, Snowflake,
 };
-use reqwest::{header, IntoUrl, Method, Response, StatusCode, Version};
+use core::{borrow::Borrow, time::Duration};
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use reqwest::{
+  header::{self, HeaderMap, HeaderName, HeaderValue},
+  Method, Response, StatusCode,
+};
 use serde::{de::DeserializeOwned, Deserialize};
+use std::{
+  collections::HashSet,
+  sync::{Arc, Mutex},
+  time::Instant,
+};
+use tokio::{sync::Semaphore, time::sleep};
 
-cfg_if::cfg_if! {
-  if #[cfg(feature = "autoposter")] {
-    use crate::autoposter;
-    use std::sync::Arc;
+#[allow(deprecated)]
+use crate::bot::Stats;
 
-    type SyncedClient = Arc<InnerClient>;
-  } else {
-    type SyncedClient = InnerClient;
-  }
-}
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+
+#[cfg(feature = "autoposter")]
+use crate::autoposter;
+
+#[cfg(feature = "cache")]
+use std::collections::HashMap;
 
 #[derive(Deserialize)]
-#[serde(rename = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
 struct Ratelimit {
   retry_after: u16,
 }
 
+/// The latest ratelimit state reported by [Top.gg](https://top.gg) through its response headers. (See [`Client::last_ratelimit_info`])
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimitInfo {
+  /// The maximum amount of requests that can be sent within the current ratelimit window. (`X-RateLimit-Limit`)
+  pub limit: u32,
+
+  /// The amount of requests that can still be sent within the current ratelimit window. (`X-RateLimit-Remaining`)
+  pub remaining: u32,
+
+  /// How long until the current ratelimit window resets. (`X-RateLimit-Reset`)
+  pub reset: Duration,
+}
+
+impl RateLimitInfo {
+  fn from_headers(headers: &HeaderMap) -> Option<Self> {
+    fn get<T: core::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+      headers.get(name)?.to_str().ok()?.trim().parse().ok()
+    }
+
+    Some(Self {
+      limit: get(headers, "x-ratelimit-limit")?,
+      remaining: get(headers, "x-ratelimit-remaining")?,
+      reset: Duration::try_from_secs_f64(get(headers, "x-ratelimit-reset")?).ok()?,
+    })
+  }
+}
+
+// paths are relative to the client's base URL.
 macro_rules! api {
   ($e:literal) => {
-    concat!("https://top.gg/api", $e)
+    $e
   };
 
   ($e:literal, $($rest:tt)*) => {
-    format!(api!($e), $($rest)*)
+    format!($e, $($rest)*)
   };
 }
 
+/// The default timeout for every request sent to the [Top.gg API](https://docs.top.gg).
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The smallest amount of voters a full page can have. A page shorter than this is the last one.
+const VOTERS_PER_PAGE: usize = 100;
+
+/// The `User-Agent` identifying this SDK, appended to any custom one.
+const USER_AGENT: &str = "topgg (https://github.com/top-gg/rust-sdk) Rust";
+
+/// The default base URL of the [Top.gg API](https://docs.top.gg).
+const DEFAULT_BASE_URL: &str = "https://top.gg/api";
+
+/// The longest ratelimit a request waits for before being retried. Longer ratelimits are returned right away.
+const MAX_RATELIMIT_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// The delay before the first retry of a request that failed due to a transient network error, doubling on each subsequent retry.
+const TRANSIENT_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// The longest delay between retries of a request that failed due to a transient network error.
+const MAX_TRANSIENT_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+#[inline(always)]
+pub(crate) fn transient_retry_delay(retries: u8) -> Duration {
+  TRANSIENT_RETRY_DELAY
+    .saturating_mul(2u32.saturating_pow(retries as _))
+    .min(MAX_TRANSIENT_RETRY_DELAY)
+}
+
+#[cfg(feature = "cache")]
+#[derive(Debug)]
+struct BotCache {
+  ttl: Duration,
+  bots: Mutex<HashMap<u64, (Instant, Bot)>>,
+}
+
+#[cfg(feature = "cache")]
+impl BotCache {
+  #[inline(always)]
+  fn new(ttl: Duration) -> Self {
+    Self {
+      ttl,
+      bots: Mutex::new(HashMap::new()),
+    }
+  }
+
+  fn get(&self, id: u64) -> Option<Bot> {
+    let mut bots = self.bots.lock().unwrap();
+
+    match bots.get(&id) {
+      Some((cached_at, bot)) if cached_at.elapsed() < self.ttl => Some(bot.clone()),
+      Some(_) => {
+        bots.remove(&id);
+        None
+      }
+      _ => None,
+    }
+  }
+
+  #[inline(always)]
+  fn insert(&self, bot: &Bot) {
+    self
+      .bots
+      .lock()
+      .unwrap()
+      .insert(bot.id, (Instant::now(), bot.clone()));
+  }
+}
+
 #[derive(Debug)]
 pub struct InnerClient {
   http: reqwest::Client,
   id: u64,
   token: String,
+  timeout: Duration,
+  base_url: String,
+  user_agent: String,
+  default_headers: HeaderMap,
+  ratelimit_retries: u8,
+  transient_retries: u8,
+  concurrency: Option<Semaphore>,
+  ratelimit_info: Mutex<Option<RateLimitInfo>>,
+  #[cfg(feature = "cache")]
+  bot_cache: Option<BotCache>,
 }
 
 // this is implemented here because autoposter needs to access this struct from a different thread.
 impl InnerClient {
-  pub(crate) fn new(token: String) -> Self {
+  pub(crate) fn new(builder: ClientBuilder) -> Self {
     Self {
-      http: reqwest::Client::new(),
-      id: util::id_from_token(&token),
-      token,
+      http: builder.http.unwrap_or_default(),
+      id: util::id_from_token(&builder.token),
+      token: builder.token,
+      timeout: builder.timeout,
+      base_url: builder.base_url,
+      user_agent: match builder.user_agent {
+        Some(user_agent) => format!("{user_agent} {USER_AGENT}"),
+        _ => String::from(USER_AGENT),
+      },
+      default_headers: builder.default_headers,
+      ratelimit_retries: builder.ratelimit_retries,
+      transient_retries: builder.transient_retries,
+      concurrency: builder.max_concurrent.map(Semaphore::new),
+      ratelimit_info: Mutex::new(None),
+      #[cfg(feature = "cache")]
+      bot_cache: builder.cache_ttl.map(BotCache::new),
     }
   }
 
-  async fn send_inner(&self, method: Method, url: impl IntoUrl, body: Vec<u8>) -> Result<Response> {
-    match self
+  async fn send_once(&self, method: Method, path: &str, body: Vec<u8>) -> Result<Response> {
+    let _permit = match &self.concurrency {
+      Some(concurrency) => Some(concurrency.acquire().await.unwrap()),
+      _ => None,
+    };
+
+    let request = self
       .http
-      .execute(
-        self
-          .http
-          .request(method, url)
-          .header(header::AUTHORIZATION, &self.token)
-          .header(header::CONNECTION, "close")
-          .header(header::CONTENT_LENGTH, body.len())
-          .header(header::CONTENT_TYPE, "application/json")
-          .header(
-            header::USER_AGENT,
-            "topgg (https://github.com/top-gg/rust-sdk) Rust",
-          )
-          .version(Version::HTTP_11)
-          .body(body)
-          .build()
-          .unwrap(),
-      )
-      .await
-    {
+      .request(method, format!("{}{path}", self.base_url))
+      .headers(self.default_headers.clone())
+      .header(header::AUTHORIZATION, &self.token)
+      .header(header::CONTENT_LENGTH, body.len())
+      .header(header::CONTENT_TYPE, "application/json")
+      .header(header::USER_AGENT, &self.user_agent)
+      .timeout(self.timeout)
+      .body(body)
+      .build()
+      .map_err(|err| Error::InternalClientError(Arc::new(err)))?;
+
+    match self.http.execute(request).await {
       Ok(response) => {
         let status = response.status();
 
+        #[cfg(feature = "tracing")]
+        tracing::Span::current().record("status", status.as_u16());
+
+        if let Some(info) = RateLimitInfo::from_headers(response.headers()) {
+          #[cfg(feature = "tracing")]
+          tracing::trace!(
+            limit = info.limit,
+            remaining = info.remaining,
+            reset = ?info.reset,
+            "received ratelimit info"
+          );
+
+          self.ratelimit_info.lock().unwrap().replace(info);
+        }
+
         if status.is_success() {
           Ok(response)
         } else {
           Err(match status {
-            StatusCode::UNAUTHORIZED => panic!("Invalid Top.gg API token."),
+            StatusCode::UNAUTHORIZED => Error::Unauthorized,
             StatusCode::NOT_FOUND => Error::NotFound,
             StatusCode::TOO_MANY_REQUESTS => match util::parse_json::<Ratelimit>(response).await {
               Ok(ratelimit) => Error::Ratelimit {
                 retry_after: ratelimit.retry_after,
               },
-              _ => Error::InternalServerError,
+              Err(err) => err,
+            },
+            _ => Error::InternalServerError {
+              status: status.as_u16(),
+              body: util::truncate_body(&response.text().await.unwrap_or_default()),
             },
-            _ => Error::InternalServerError,
           })
         }
       }
 
-      Err(err) => Err(Error::InternalClientError(err)),
+      Err(err) => Err(Error::InternalClientError(Arc::new(err))),
+    }
+  }
+
+  async fn send_inner(
+    &self,
+    method: Method,
+    path: impl AsRef<str>,
+    body: Vec<u8>,
+  ) -> Result<Response> {
+    let path = path.as_ref();
+    let mut retries = 0;
+    let mut transient_retries = 0;
+
+    loop {
+      let attempt = self.send_once(method.clone(), path, body.clone());
+
+      cfg_if::cfg_if! {
+        if #[cfg(feature = "tracing")] {
+          let span = tracing::debug_span!(
+            "topgg_request",
+            %method,
+            path,
+            status = tracing::field::Empty,
+            elapsed = tracing::field::Empty,
+          );
+
+          let start = Instant::now();
+          let result = attempt.instrument(span.clone()).await;
+
+          span.record("elapsed", tracing::field::debug(start.elapsed()));
+
+          match &result {
+            Ok(_) => tracing::debug!(parent: &span, "request succeeded"),
+            Err(err @ Error::NotFound) => tracing::debug!(parent: &span, %err, "request failed"),
+            Err(err @ Error::Ratelimit { .. }) => tracing::warn!(parent: &span, %err, "request failed"),
+            Err(err) => tracing::error!(parent: &span, %err, "request failed"),
+          }
+        } else {
+          let result = attempt.await;
+        }
+      }
+
+      match result {
+        Err(err @ Error::Ratelimit { .. })
+          if retries < self.ratelimit_retries
+            && err.retry_after().unwrap() <= MAX_RATELIMIT_RETRY_DELAY =>
+        {
+          retries += 1;
+
+          sleep(err.retry_after().unwrap()).await;
+        }
+
+        Err(Error::InternalClientError(err))
+          if method == Method::GET
+            && (err.is_connect() || err.is_timeout())
+            && transient_retries < self.transient_retries =>
+        {
+          sleep(transient_retry_delay(transient_retries)).await;
+
+          transient_retries += 1;
+        }
+
+        result => return result,
+      }
     }
   }
 
@@ -101,18 +313,22 @@ impl InnerClient {
   pub(crate) async fn send<T>(
     &self,
     method: Method,
-    url: impl IntoUrl,
+    path: impl AsRef<str>,
     body: Option<Vec<u8>>,
   ) -> Result<T>
   where
     T: DeserializeOwned,
   {
-    match self.send_inner(method, url, body.unwrap_or_default()).await {
+    match self
+      .send_inner(method, path, body.unwrap_or_default())
+      .await
+    {
       Ok(response) => util::parse_json(response).await,
       Err(err) => Err(err),
     }
   }
 
+  #[allow(deprecated)]
   pub(crate) async fn post_stats(&self, new_stats: &Stats) -> Result<()> {
     self
       .send_inner(
@@ -125,11 +341,179 @@ impl InnerClient {
   }
 }
 
+/// A struct for configuring a [`Client`] before building it. See [`Client::builder`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use core::time::Duration;
+/// use topgg::ClientBuilder;
+///
+/// let client = ClientBuilder::new(env!("TOPGG_TOKEN").to_string())
+///   .timeout(Duration::from_secs(5))
+///   .build();
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct ClientBuilder {
+  token: String,
+  http: Option<reqwest::Client>,
+  timeout: Duration,
+  base_url: String,
+  user_agent: Option<String>,
+  default_headers: HeaderMap,
+  ratelimit_retries: u8,
+  transient_retries: u8,
+  max_concurrent: Option<usize>,
+  #[cfg(feature = "cache")]
+  cache_ttl: Option<Duration>,
+}
+
+impl ClientBuilder {
+  /// Creates a brand new client builder from a [Top.gg](https://top.gg) token.
+  ///
+  /// To get your [Top.gg](https://top.gg) token, [view this tutorial](https://github.com/top-gg/rust-sdk/assets/60427892/d2df5bd3-bc48-464c-b878-a04121727bff).
+  #[inline(always)]
+  pub fn new(token: String) -> Self {
+    Self {
+      token,
+      http: None,
+      timeout: DEFAULT_TIMEOUT,
+      base_url: String::from(DEFAULT_BASE_URL),
+      user_agent: None,
+      default_headers: HeaderMap::new(),
+      ratelimit_retries: 0,
+      transient_retries: 0,
+      max_concurrent: None,
+      #[cfg(feature = "cache")]
+      cache_ttl: None,
+    }
+  }
+
+  /// Uses an existing [`reqwest::Client`] instead of creating a new one.
+  #[inline(always)]
+  pub fn http(mut self, http: reqwest::Client) -> Self {
+    self.http.replace(http);
+    self
+  }
+
+  /// Sets the timeout for every request sent to the [Top.gg API](https://docs.top.gg). Defaults to 10 seconds.
+  ///
+  /// Requests that time out will resolve to an [`InternalClientError`][crate::Error::InternalClientError].
+  #[inline(always)]
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = timeout;
+    self
+  }
+
+  /// Sets the base URL every request path is appended to. Defaults to `https://top.gg/api`.
+  ///
+  /// This is mostly useful for pointing the client to a mock server in tests.
+  #[inline(always)]
+  pub fn base_url(mut self, base_url: String) -> Self {
+    self.base_url = base_url.trim_end_matches('/').to_owned();
+    self
+  }
+
+  /// Sets a custom `User-Agent` to identify your application with. The SDK's own identifier will still be appended after it.
+  #[inline(always)]
+  pub fn user_agent(mut self, user_agent: String) -> Self {
+    self.user_agent.replace(user_agent);
+    self
+  }
+
+  /// Adds a header to be sent with every request, like a routing header required by an egress proxy. Calling this multiple times accumulates every header, even ones with the same name.
+  ///
+  /// The `Authorization`, `Content-Length`, `Content-Type` and `User-Agent` headers are managed by the client itself, so they are ignored here. (See [`user_agent`][ClientBuilder::user_agent] for customizing the latter)
+  ///
+  /// # Panics
+  ///
+  /// Panics if `name` is not a valid header name or `value` is not a valid header value.
+  pub fn default_header<K, V>(mut self, name: K, value: V) -> Self
+  where
+    HeaderName: TryFrom<K>,
+    HeaderValue: TryFrom<V>,
+  {
+    let name = HeaderName::try_from(name)
+      .ok()
+      .expect("invalid header name");
+    let value = HeaderValue::try_from(value)
+      .ok()
+      .expect("invalid header value");
+
+    if ![
+      header::AUTHORIZATION,
+      header::CONTENT_LENGTH,
+      header::CONTENT_TYPE,
+      header::USER_AGENT,
+    ]
+    .contains(&name)
+    {
+      self.default_headers.append(name, value);
+    }
+
+    self
+  }
+
+  /// Makes the client wait and resend a request up to `max_retries` times whenever it gets ratelimited. Defaults to 0 (disabled).
+  ///
+  /// Once the retries are exhausted, the request will resolve to a [`Ratelimit`][crate::Error::Ratelimit] error as usual. Keep in mind that each retry waits for the entire ratelimit duration reported by [Top.gg](https://top.gg), so ratelimits longer than a minute are never retried and resolve to the error right away.
+  #[inline(always)]
+  pub fn retry_on_ratelimit(mut self, max_retries: u8) -> Self {
+    self.ratelimit_retries = max_retries;
+    self
+  }
+
+  /// Makes the client resend a `GET` request up to `max_retries` times whenever it fails to connect or times out, waiting exponentially longer between each retry starting from 250 milliseconds, up to 30 seconds. Defaults to 0 (disabled).
+  ///
+  /// Non-idempotent requests like [posting stats][crate::Client::post_stats] are never retried this way. Once the retries are exhausted, the request will resolve to an [`InternalClientError`][crate::Error::InternalClientError] as usual.
+  #[inline(always)]
+  pub fn retry_transient(mut self, max_retries: u8) -> Self {
+    self.transient_retries = max_retries;
+    self
+  }
+
+  /// Limits the amount of requests the client can send to the [Top.gg API](https://docs.top.gg) at the same time, making the rest wait for their turn. This is unlimited by default.
+  ///
+  /// This helps staying under [Top.gg](https://top.gg)'s ratelimits proactively when sending lots of requests at once. A `max_concurrent` of zero is treated as one.
+  #[inline(always)]
+  pub fn max_concurrent(mut self, max_concurrent: usize) -> Self {
+    self.max_concurrent.replace(max_concurrent.max(1));
+    self
+  }
+
+  /// Caches bots fetched with [`get_bot`][Client::get_bot] in memory for `ttl`, so repeated calls within that duration don't send another request. Caching is disabled by default.
+  ///
+  /// See [`Client::clear_cache`] for invalidating the cache manually.
+  #[inline(always)]
+  #[cfg(feature = "cache")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+  pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+    self.cache_ttl.replace(ttl);
+    self
+  }
+
+  /// Builds the [`Client`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the [Top.gg API](https://docs.top.gg) token is malformed.
+  pub fn build(self) -> Client {
+    Client {
+      inner: Arc::new(InnerClient::new(self)),
+    }
+  }
+}
+
 /// A struct representing a [Top.gg API](https://docs.top.gg) client instance.
+///
+/// Cloning this struct is cheap as the underlying client is reference-counted, so it can be freely shared across tasks.
 #[must_use]
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Client {
-  inner: SyncedClient,
+  inner: Arc<InnerClient>,
 }
 
 impl Client {
@@ -138,51 +522,180 @@ impl Client {
   /// To get your [Top.gg](https://top.gg) token, [view this tutorial](https://github.com/top-gg/rust-sdk/assets/60427892/d2df5bd3-bc48-464c-b878-a04121727bff).
   #[inline(always)]
   pub fn new(token: String) -> Self {
-    let inner = InnerClient::new(token);
+    ClientBuilder::new(token).build()
+  }
 
-    #[cfg(feature = "autoposter")]
-    let inner = Arc::new(inner);
+  /// Creates a [`ClientBuilder`] from a [Top.gg](https://top.gg) token for configuring the client before building it.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// use core::time::Duration;
+  /// use topgg::Client;
+  ///
+  /// let client = Client::builder(env!("TOPGG_TOKEN").to_string())
+  ///   .timeout(Duration::from_secs(5))
+  ///   .build();
+  /// ```
+  #[inline(always)]
+  pub fn builder(token: String) -> ClientBuilder {
+    ClientBuilder::new(token)
+  }
 
-    Self { inner }
+  /// Creates a brand new client instance from a [Top.gg](https://top.gg) token and an existing [`reqwest::Client`].
+  ///
+  /// This is useful if you want the [Top.gg API](https://docs.top.gg) requests to share the same connection pool, proxy or TLS settings as the rest of your application.
+  #[inline(always)]
+  pub fn with_http(token: String, http: reqwest::Client) -> Self {
+    ClientBuilder::new(token).http(http).build()
+  }
+
+  /// Retrieves the underlying [`reqwest::Client`] used by this client.
+  ///
+  /// This is useful for sharing its connection pool and configuration with the rest of your application. (See [`ClientBuilder::http`])
+  #[must_use]
+  #[inline(always)]
+  pub fn http(&self) -> &reqwest::Client {
+    &self.inner.http
+  }
+
+  /// Retrieves the ratelimit state reported by the latest response from [Top.gg](https://top.gg), if any.
+  ///
+  /// This is useful for slowing down preemptively before getting ratelimited. This is `None` if no response reported it yet.
+  #[must_use]
+  #[inline(always)]
+  pub fn last_ratelimit_info(&self) -> Option<RateLimitInfo> {
+    *self.inner.ratelimit_info.lock().unwrap()
   }
 
   /// Fetches a listed bot from a Discord ID.
   ///
   /// # Panics
   ///
-  /// Panics if any of the following conditions are met:
-  /// - The ID argument is a string but not numeric
-  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// Panics if the ID argument is a string but not numeric.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The requested bot is not listed on [Top.gg](https://top.gg) ([`NotFound`][crate::Error::NotFound])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
   pub async fn get_bot<I>(&self, id: I) -> Result<Bot>
   where
     I: Snowflake,
   {
-    self
+    let id = id.as_snowflake();
+
+    #[cfg(feature = "cache")]
+    if let Some(bot) = self
       .inner
-      .send(Method::GET, api!("/bots/{}", id.as_snowflake()), None)
-      .await
+      .bot_cache
+      .as_ref()
+      .and_then(|cache| cache.get(id))
+    {
+      return Ok(bot);
+    }
+
+    let bot: Bot = self
+      .inner
+      .send(Method::GET, api!("/bots/{}", id), None)
+      .await?;
+
+    #[cfg(feature = "cache")]
+    if let Some(cache) = &self.inner.bot_cache {
+      cache.insert(&bot);
+    }
+
+    Ok(bot)
   }
 
-  /// Fetches your bot's statistics.
+  /// Fetches your own bot, whose ID is read from the client's [Top.gg API](https://docs.top.gg) token. This saves you from having to store your bot's ID separately.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - Your bot is not listed on [Top.gg](https://top.gg) ([`NotFound`][crate::Error::NotFound])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[inline(always)]
+  pub async fn get_self(&self) -> Result<Bot> {
+    self.get_bot(self.inner.id).await
+  }
+
+  /// Fetches a listed bot from a Discord ID, returning `None` instead of an error if it's not listed on [Top.gg](https://top.gg). This also doesn't panic if the ID is invalid.
+  ///
+  /// This is useful for checking many IDs, or IDs coming from untrusted input like slash command arguments or web forms.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - The ID argument can't be interpreted as a Discord ID ([`InvalidId`][crate::Error::InvalidId])
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  pub async fn try_get_bot<I>(&self, id: I) -> Result<Option<Bot>>
+  where
+    I: Snowflake,
+  {
+    let id = id.try_as_snowflake().map_err(|_| Error::InvalidId)?;
+
+    match self.get_bot(id).await {
+      Ok(bot) => Ok(Some(bot)),
+      Err(Error::NotFound) => Ok(None),
+      Err(err) => Err(err),
+    }
+  }
+
+  /// Clears every bot cached by [`get_bot`][Client::get_bot]. This has no effect if caching is disabled. (See [`ClientBuilder::cache_ttl`])
+  #[inline(always)]
+  #[cfg(feature = "cache")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "cache")))]
+  pub fn clear_cache(&self) {
+    if let Some(cache) = &self.inner.bot_cache {
+      cache.bots.lock().unwrap().clear();
+    }
+  }
+
+  /// Fetches multiple listed bots from their Discord IDs, sending at most `concurrency` requests at a time.
+  ///
+  /// The results are returned in the same order as the IDs, and each of them succeeds or fails independently, so one unlisted bot doesn't fail the whole batch. A `concurrency` of zero is treated as one.
   ///
   /// # Panics
   ///
-  /// Panics if the client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// Panics if any of the IDs is a string but not numeric.
+  pub async fn get_bots_by_ids<I, T>(&self, ids: I, concurrency: usize) -> Vec<Result<Bot>>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    stream::iter(ids)
+      .map(|id| self.get_bot(id))
+      .buffered(concurrency.max(1))
+      .collect()
+      .await
+  }
+
+  /// Fetches your bot's statistics.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[allow(deprecated)]
   pub async fn get_stats(&self) -> Result<Stats> {
     self
       .inner
@@ -190,61 +703,302 @@ impl Client {
       .await
   }
 
-  /// Posts your bot's server count.
+  /// Fetches the server count of any listed bot from its Discord ID, without fetching the whole [`Bot`]. This is `None` if the bot hasn't posted its server count.
   ///
   /// # Panics
   ///
-  /// Panics if the client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// Panics if the ID argument is a string but not numeric.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The requested bot is not listed on [Top.gg](https://top.gg) ([`NotFound`][crate::Error::NotFound])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[allow(deprecated)]
+  pub async fn get_bot_stats<I>(&self, id: I) -> Result<Option<usize>>
+  where
+    I: Snowflake,
+  {
+    self
+      .inner
+      .send::<Stats>(Method::GET, api!("/bots/{}/stats", id.as_snowflake()), None)
+      .await
+      .map(|stats| stats.server_count())
+  }
+
+  /// Checks if the client's [Top.gg API](https://docs.top.gg) token is valid by sending a lightweight authenticated request.
+  ///
+  /// Returns `false` if [Top.gg](https://top.gg) rejects the token instead of an [`Unauthorized`][crate::Error::Unauthorized] error.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  pub async fn validate_token(&self) -> Result<bool> {
+    match self
+      .inner
+      .send_inner(Method::GET, api!("/bots/stats"), Vec::new())
+      .await
+    {
+      Ok(_) => Ok(true),
+      Err(Error::Unauthorized) => Ok(false),
+      Err(err) => Err(err),
+    }
+  }
+
+  /// Checks if [Top.gg](https://top.gg) can be reached by sending a lightweight request, returning its round-trip latency.
+  ///
+  /// This is useful for health checks like readiness probes. Retries configured through the [`ClientBuilder`] apply to it as well, so the latency includes them.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  pub async fn ping(&self) -> Result<Duration> {
+    let start = Instant::now();
+
+    self
+      .inner
+      .send_inner(Method::GET, api!("/weekend"), Vec::new())
+      .await
+      .map(|_| start.elapsed())
+  }
+
+  /// Sends an authenticated `GET` request to any [Top.gg API](https://docs.top.gg) path relative to the base URL (like `/bots/stats`), returning its parsed JSON response.
+  ///
+  /// This is an escape hatch for endpoints that aren't supported by this SDK yet. An empty response body results in [`Null`][serde_json::Value::Null].
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that isn't valid JSON ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The requested resource does not exist ([`NotFound`][crate::Error::NotFound])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
   #[inline(always)]
-  pub async fn post_stats(&self, new_stats: Stats) -> Result<()> {
-    self.inner.post_stats(&new_stats).await
+  pub async fn get_raw(&self, path: &str) -> Result<serde_json::Value> {
+    self.send_raw(Method::GET, path, Vec::new()).await
   }
 
-  /// Fetches your bot's last 1000 voters.
+  /// Sends an authenticated `POST` request with a JSON body to any [Top.gg API](https://docs.top.gg) path relative to the base URL, returning its parsed JSON response. (See [`get_raw`][Client::get_raw])
   ///
-  /// # Panics
+  /// # Errors
   ///
-  /// Panics if the client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// See [`get_raw`][Client::get_raw].
+  #[inline(always)]
+  pub async fn post_raw(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+    self
+      .send_raw(Method::POST, path, serde_json::to_vec(body).unwrap())
+      .await
+  }
+
+  async fn send_raw(&self, method: Method, path: &str, body: Vec<u8>) -> Result<serde_json::Value> {
+    let response = self
+      .inner
+      .send_inner(method, format!("/{}", path.trim_start_matches('/')), body)
+      .await?;
+
+    if response.content_length() == Some(0) {
+      Ok(serde_json::Value::Null)
+    } else {
+      util::parse_json(response).await
+    }
+  }
+
+  /// Posts your bot's server count. This accepts either an owned or a borrowed [`Stats`], so existing [`Stats`] (like ones from [`Stats::from_context`]) can be posted as-is.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[inline(always)]
+  #[allow(deprecated)]
+  pub async fn post_stats<S>(&self, new_stats: S) -> Result<()>
+  where
+    S: Borrow<Stats>,
+  {
+    self.inner.post_stats(new_stats.borrow()).await
+  }
+
+  /// Fetches your bot's last 1000 voters.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[inline(always)]
   pub async fn get_voters(&self) -> Result<Vec<Voter>> {
+    self.get_voters_page(1).await
+  }
+
+  /// Fetches a page of your bot's voters, letting you go further back than [`get_voters`][Client::get_voters]. Pages start from 1, and a page of 0 is treated as 1.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  pub async fn get_voters_page(&self, page: u16) -> Result<Vec<Voter>> {
     self
       .inner
-      .send(Method::GET, api!("/bots/{}/votes", self.inner.id), None)
+      .send(
+        Method::GET,
+        api!("/bots/{}/votes?page={}", self.inner.id, page.max(1)),
+        None,
+      )
       .await
   }
 
-  pub(crate) async fn get_bots_inner(&self, query: String) -> Result<Vec<Bot>> {
-    self
+  /// Fetches the raw JSON response body of a page of your bot's voters without deserializing it. Pages start from 1, and a page of 0 is treated as 1.
+  ///
+  /// This is meant to be deserialized into [`VoterRef`][crate::voter::VoterRef]s borrowing from it, avoiding allocations when processing lots of voters.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  pub async fn get_voters_page_raw(&self, page: u16) -> Result<Vec<u8>> {
+    let response = self
       .inner
-      .send::<Bots>(Method::GET, api!("/bots{}", query), None)
+      .send_inner(
+        Method::GET,
+        api!("/bots/{}/votes?page={}", self.inner.id, page.max(1)),
+        Vec::new(),
+      )
+      .await?;
+
+    response
+      .bytes()
       .await
-      .map(|res| res.results)
+      .map(Vec::from)
+      .map_err(|err| Error::InternalClientError(Arc::new(err)))
   }
 
-  /// Queries/searches through the [Top.gg](https://top.gg) database to look for matching listed Discord bots.
+  /// Returns a [`Stream`] of a page of your bot's voters, deserializing each of them as soon as it arrives instead of buffering the whole response first. Pages start from 1, and a page of 0 is treated as 1.
   ///
-  /// # Panics
+  /// This is useful in memory-constrained environments. (See [`get_voters_page`][Client::get_voters_page] for the buffered alternative) The stream ends right after yielding an error.
+  pub fn voters_page_stream(
+    &self,
+    page: u16,
+  ) -> impl Stream<Item = Result<Voter>> + Send + 'static {
+    let client = self.clone();
+
+    stream::once(async move {
+      client
+        .inner
+        .send_inner(
+          Method::GET,
+          api!("/bots/{}/votes?page={}", client.inner.id, page.max(1)),
+          Vec::new(),
+        )
+        .await
+    })
+    .map_ok(util::parse_json_array_stream)
+    .try_flatten()
+  }
+
+  /// Returns a [`Stream`] of all of your bot's voters, transparently fetching successive pages until there are none left.
+  ///
+  /// The stream ends right after yielding an error.
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// use futures_util::TryStreamExt;
+  /// use topgg::Client;
+  ///
+  /// # async fn run() -> topgg::Result<()> {
+  /// let client = Client::new(env!("TOPGG_TOKEN").to_string());
+  /// let voters: Vec<_> = client.voters_stream().try_collect().await?;
   ///
-  /// Panics if any of the client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized).
+  /// println!("{} voters", voters.len());
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn voters_stream(&self) -> impl Stream<Item = Result<Voter>> + Send + 'static {
+    let client = self.clone();
+
+    stream::try_unfold(Some(1u16), move |page| {
+      let client = client.clone();
+
+      async move {
+        let Some(page) = page else {
+          return Ok(None);
+        };
+
+        let voters = client.get_voters_page(page).await?;
+
+        if voters.is_empty() {
+          return Ok(None);
+        }
+
+        let next = (voters.len() >= VOTERS_PER_PAGE)
+          .then(|| page.checked_add(1))
+          .flatten();
+
+        Ok(Some((stream::iter(voters.into_iter().map(Ok)), next)))
+      }
+    })
+    .try_flatten()
+  }
+
+  #[inline(always)]
+  pub(crate) async fn get_bots_inner(&self, query: String) -> Result<Bots> {
+    let mut bots: Bots = self
+      .inner
+      .send(Method::GET, api!("/bots{}", query), None)
+      .await?;
+
+    for bot in &mut bots.results {
+      bot.source = BotSource::Search;
+    }
+
+    Ok(bots)
+  }
+
+  #[inline(always)]
+  pub(crate) async fn get_bot_summaries_inner(&self, query: String) -> Result<Bots<BotSummary>> {
+    self
+      .inner
+      .send(Method::GET, api!("/bots{}", query), None)
+      .await
+  }
+
+  /// Queries/searches through the [Top.gg](https://top.gg) database to look for matching listed Discord bots.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
   ///
   /// # Examples
@@ -273,21 +1027,21 @@ impl Client {
     GetBots::new(self)
   }
 
-  /// Checks if the specified user has voted your bot.
+  /// Retrieves the amount of times the specified user has voted your bot within the current voting window, which is zero if they haven't voted.
   ///
   /// # Panics
   ///
-  /// Panics if any of the following conditions are met:
-  /// - The user ID argument is a string and it's not a valid ID (expected things like `"123456789"`)
-  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// Panics if the user ID argument is a string and it's not a valid ID (expected things like `"123456789"`).
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
-  pub async fn has_voted<I>(&self, user_id: I) -> Result<bool>
+  pub async fn get_vote_count<I>(&self, user_id: I) -> Result<u32>
   where
     I: Snowflake,
   {
@@ -303,20 +1057,144 @@ impl Client {
         None,
       )
       .await
-      .map(|res| res.voted != 0)
+      .map(|res| res.voted)
   }
 
-  /// Checks if the weekend multiplier is active.
+  /// Checks if the specified user has voted your bot. (See [`get_vote_count`][Client::get_vote_count] for the amount of votes)
   ///
   /// # Panics
   ///
-  /// Panics if the client uses an invalid [Top.gg API](https://docs.top.gg) token (unauthorized)
+  /// Panics if the user ID argument is a string and it's not a valid ID (expected things like `"123456789"`).
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[inline(always)]
+  pub async fn has_voted<I>(&self, user_id: I) -> Result<bool>
+  where
+    I: Snowflake,
+  {
+    self.get_vote_count(user_id).await.map(|voted| voted != 0)
+  }
+
+  /// Checks if the specified user has voted your bot, without panicking if the user ID is invalid.
+  ///
+  /// This is useful for IDs coming from untrusted input, like slash command arguments or web forms.
   ///
   /// # Errors
   ///
   /// Errors if any of the following conditions are met:
+  /// - The user ID argument can't be interpreted as a Discord ID ([`InvalidId`][crate::Error::InvalidId])
   /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
   /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  #[inline(always)]
+  pub async fn try_has_voted<I>(&self, user_id: I) -> Result<bool>
+  where
+    I: Snowflake,
+  {
+    let user_id = user_id.try_as_snowflake().map_err(|_| Error::InvalidId)?;
+
+    self.has_voted(user_id).await
+  }
+
+  /// Checks if each of the specified users has voted your bot, sending at most `concurrency` requests at a time.
+  ///
+  /// The results are returned in the same order as the IDs, each paired with its user ID, and each of them succeeds or fails independently. A `concurrency` of zero is treated as one.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the user IDs is a string and it's not a valid ID (expected things like `"123456789"`).
+  pub async fn has_voted_many<I, T>(
+    &self,
+    user_ids: I,
+    concurrency: usize,
+  ) -> Vec<(u64, Result<bool>)>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    stream::iter(user_ids)
+      .map(|user_id| {
+        let user_id = user_id.as_snowflake();
+
+        async move { (user_id, self.has_voted(user_id).await) }
+      })
+      .buffered(concurrency.max(1))
+      .collect()
+      .await
+  }
+
+  /// Retrieves which of the specified users have voted your bot, sending at most `concurrency` requests at a time.
+  ///
+  /// Repeated user IDs are only checked once. A `concurrency` of zero is treated as one.
+  ///
+  /// # Panics
+  ///
+  /// Panics if any of the user IDs is a string and it's not a valid ID (expected things like `"123456789"`).
+  ///
+  /// # Errors
+  ///
+  /// Errors as soon as any of the checks fails, with any of the following conditions:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
+  /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
+  ///
+  /// # Examples
+  ///
+  /// Basic usage:
+  ///
+  /// ```rust,no_run
+  /// # async fn example(client: topgg::Client) -> topgg::Result<()> {
+  /// let voters = client.voters_among([661200758510977084u64, 264811613708746752], 4).await?;
+  ///
+  /// if voters.contains(&661200758510977084) {
+  ///   // grant rewards...
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn voters_among<I, T>(&self, user_ids: I, concurrency: usize) -> Result<HashSet<u64>>
+  where
+    I: IntoIterator<Item = T>,
+    T: Snowflake,
+  {
+    let user_ids: HashSet<u64> = user_ids
+      .into_iter()
+      .map(|user_id| user_id.as_snowflake())
+      .collect();
+
+    stream::iter(user_ids)
+      .map(|user_id| async move {
+        self
+          .has_voted(user_id)
+          .await
+          .map(|voted| voted.then_some(user_id))
+      })
+      .buffer_unordered(concurrency.max(1))
+      .try_filter_map(|user_id| async move { Ok(user_id) })
+      .try_collect()
+      .await
+  }
+
+  /// Checks if the weekend multiplier is active.
+  ///
+  /// # Errors
+  ///
+  /// Errors if any of the following conditions are met:
+  /// - An internal error from the client itself preventing it from sending a HTTP request to [Top.gg](https://top.gg) ([`InternalClientError`][crate::Error::InternalClientError])
+  /// - An unexpected response from the [Top.gg](https://top.gg) servers ([`InternalServerError`][crate::Error::InternalServerError])
+  /// - A successful response from [Top.gg](https://top.gg) that can't be deserialized ([`Deserialize`][crate::Error::Deserialize])
+  /// - The client uses an invalid [Top.gg API](https://docs.top.gg) token ([`Unauthorized`][crate::Error::Unauthorized])
   /// - The client is being ratelimited from sending more HTTP requests ([`Ratelimit`][crate::Error::Ratelimit])
   pub async fn is_weekend(&self) -> Result<bool> {
     self
@@ -327,6 +1205,30 @@ impl Client {
   }
 }
 
+impl From<String> for Client {
+  /// Creates a brand new client instance from a [Top.gg](https://top.gg) token. (See [`Client::new`])
+  ///
+  /// # Panics
+  ///
+  /// Panics if the [Top.gg API](https://docs.top.gg) token is malformed.
+  #[inline(always)]
+  fn from(token: String) -> Self {
+    Self::new(token)
+  }
+}
+
+impl From<&str> for Client {
+  /// Creates a brand new client instance from a [Top.gg](https://top.gg) token. (See [`Client::new`])
+  ///
+  /// # Panics
+  ///
+  /// Panics if the [Top.gg API](https://docs.top.gg) token is malformed.
+  #[inline(always)]
+  fn from(token: &str) -> Self {
+    Self::new(token.to_owned())
+  }
+}
+
 cfg_if::cfg_if! {
   if #[cfg(feature = "autoposter")] {
     impl autoposter::AsClientSealed for Client {
@@ -1,36 +1,90 @@
-use core::{fmt, result};
-use std::error;
+use core::{fmt, result, time::Duration};
+use std::{error, sync::Arc};
 
 /// A struct representing an error coming from this SDK - unexpected or not.
-#[derive(Debug)]
+///
+/// Cloning this struct is cheap as the underlying [`reqwest::Error`] and [`serde_json::Error`] are reference-counted.
+#[derive(Clone, Debug)]
 pub enum Error {
   /// An unexpected internal error coming from the client itself, preventing it from sending a request to [Top.gg](https://top.gg).
-  InternalClientError(reqwest::Error),
+  InternalClientError(Arc<reqwest::Error>),
 
   /// An unexpected error coming from [Top.gg](https://top.gg)'s servers themselves.
-  InternalServerError,
+  InternalServerError {
+    /// The HTTP status code of the response.
+    status: u16,
+
+    /// The response body, truncated to at most 512 bytes. This may be empty.
+    body: String,
+  },
+
+  /// A successful response from [Top.gg](https://top.gg) can't be deserialized, either because it isn't valid JSON or because it doesn't match what this SDK expects, like when the [Top.gg API](https://docs.top.gg)'s schema changes. Unlike [`InternalServerError`][Error::InternalServerError], this is never caused by an error status code.
+  Deserialize {
+    /// The path to the field that failed to deserialize, like `[0].username`. This is `.` if it's the response body itself.
+    path: String,
+
+    /// The underlying error.
+    source: Arc<serde_json::Error>,
+  },
+
+  /// The client uses an invalid [Top.gg API](https://docs.top.gg) token. (401)
+  Unauthorized,
 
   /// The requested resource does not exist. (404)
   NotFound,
 
+  /// The provided ID can't be interpreted as a Discord snowflake/ID.
+  InvalidId,
+
   /// The client is being ratelimited from sending more HTTP requests.
   Ratelimit {
     /// The amount of seconds before the ratelimit is lifted.
     retry_after: u16,
   },
+
+  /// The [`Autoposter`][crate::Autoposter]'s thread stopped unexpectedly, like when its [`Handler`][crate::autoposter::Handler] panics. (See [`Autoposter::is_running`][crate::Autoposter::is_running])
+  #[cfg(feature = "autoposter")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "autoposter")))]
+  AutoposterStopped,
+}
+
+impl Error {
+  /// Returns how long to wait before the ratelimit is lifted if this is a [`Ratelimit`][Error::Ratelimit] error, otherwise `None`.
+  #[must_use]
+  #[inline(always)]
+  pub const fn retry_after(&self) -> Option<Duration> {
+    match self {
+      Self::Ratelimit { retry_after } => Some(Duration::from_secs(*retry_after as _)),
+      _ => None,
+    }
+  }
 }
 
 impl fmt::Display for Error {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     match self {
       Self::InternalClientError(err) => write!(f, "internal client error: {err}"),
-      Self::InternalServerError => write!(f, "internal server error"),
+      Self::InternalServerError { status, body } => {
+        write!(f, "internal server error ({status})")?;
+
+        if !body.is_empty() {
+          write!(f, ": {body}")?;
+        }
+
+        Ok(())
+      }
+      Self::Deserialize { path, source } => {
+        write!(f, "failed to deserialize the response at {path}: {source}")
+      }
+      Self::Unauthorized => write!(f, "unauthorized"),
       Self::NotFound => write!(f, "not found"),
+      Self::InvalidId => write!(f, "invalid snowflake/ID"),
       Self::Ratelimit { retry_after } => write!(
         f,
-        "this client is ratelimited, try again in {} seconds",
-        retry_after / 60
+        "this client is ratelimited, try again in {retry_after} seconds"
       ),
+      #[cfg(feature = "autoposter")]
+      Self::AutoposterStopped => write!(f, "the autoposter stopped unexpectedly"),
     }
   }
 }
@@ -39,7 +93,8 @@ impl error::Error for Error {
   #[inline(always)]
   fn source(&self) -> Option<&(dyn error::Error + 'static)> {
     match self {
-      Self::InternalClientError(err) => err.source(),
+      Self::InternalClientError(err) => Some(err.as_ref()),
+      Self::Deserialize { source, .. } => Some(source.as_ref()),
       _ => None,
     }
   }
@@ -8,6 +8,7 @@ mod test;
 
 cfg_if::cfg_if! {
   if #[cfg(feature = "api")] {
+    mod api;
     mod client;
     mod error;
     mod util;
@@ -21,11 +22,29 @@ cfg_if::cfg_if! {
     /// Voter-related structs.
     pub mod voter;
 
+    pub use api::TopggApi;
     #[doc(inline)]
+    #[allow(deprecated)]
     pub use bot::Stats;
-    pub use client::Client;
+    pub use client::{Client, ClientBuilder, RateLimitInfo};
     pub use error::{Error, Result};
-    pub use snowflake::Snowflake; // for doc purposes
+    pub use snowflake::{snowflake_timestamp, Snowflake, SnowflakeError, DISCORD_EPOCH_MS}; // for doc purposes
+  }
+}
+
+cfg_if::cfg_if! {
+  if #[cfg(feature = "blocking")] {
+    /// A blocking client for synchronous contexts.
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]
+    pub mod blocking;
+  }
+}
+
+cfg_if::cfg_if! {
+  if #[cfg(feature = "mock")] {
+    /// An in-memory test double for the API client.
+    #[cfg_attr(docsrs, doc(cfg(feature = "mock")))]
+    pub mod mock;
   }
 }
 
@@ -36,7 +55,7 @@ cfg_if::cfg_if! {
     pub mod autoposter;
 
     #[doc(inline)]
-    pub use autoposter::{Autoposter, SharedStats};
+    pub use autoposter::{Autoposter, AutoposterBuilder, SharedStats};
   }
 }
 
@@ -0,0 +1,158 @@
+use crate::{bot::Bot, voter::Voter, Error, Result, TopggApi};
+use std::{collections::HashMap, sync::Mutex};
+
+#[allow(deprecated)]
+use crate::Stats;
+
+#[derive(Default)]
+#[allow(deprecated)]
+struct State {
+  bots: HashMap<u64, Bot>,
+  bot_stats: HashMap<u64, Option<usize>>,
+  stats: Stats,
+  posted_stats: Vec<Stats>,
+  voters: Vec<Voter>,
+  vote_counts: HashMap<u64, u32>,
+  is_weekend: bool,
+  error: Option<Error>,
+}
+
+/// An in-memory [`TopggApi`] implementation with canned responses, for testing your own code without sending any HTTP requests.
+///
+/// Every response can be changed at any time through a shared reference, and:
+/// - Bots that weren't [added][MockClient::add_bot] result in a [`NotFound`][crate::Error::NotFound] error.
+/// - Users without a [vote count][MockClient::set_vote_count] haven't voted.
+/// - Posted [`Stats`] are recorded, and returned by subsequent [`get_stats`][TopggApi::get_stats] calls.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::{mock::MockClient, TopggApi};
+///
+/// # async fn example() {
+/// let client = MockClient::new();
+///
+/// client.set_vote_count(661200758510977084, 1);
+///
+/// assert!(client.has_voted(661200758510977084).await.unwrap());
+/// assert!(!client.has_voted(264811613708746752).await.unwrap());
+/// # }
+/// ```
+#[must_use]
+#[derive(Default)]
+pub struct MockClient {
+  state: Mutex<State>,
+}
+
+impl MockClient {
+  /// Creates a new mock client without any bots, voters or votes.
+  #[inline(always)]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a bot to be returned by [`get_bot`][TopggApi::get_bot], replacing any existing bot with the same ID.
+  pub fn add_bot(&self, bot: Bot) {
+    self.state.lock().unwrap().bots.insert(bot.id, bot);
+  }
+
+  /// Sets the server count returned by [`get_bot_stats`][TopggApi::get_bot_stats] for a bot.
+  pub fn set_bot_stats(&self, id: u64, server_count: Option<usize>) {
+    self
+      .state
+      .lock()
+      .unwrap()
+      .bot_stats
+      .insert(id, server_count);
+  }
+
+  /// Sets the [`Stats`] returned by [`get_stats`][TopggApi::get_stats].
+  #[allow(deprecated)]
+  pub fn set_stats(&self, stats: Stats) {
+    self.state.lock().unwrap().stats = stats;
+  }
+
+  /// Sets the voters returned by [`get_voters`][TopggApi::get_voters].
+  pub fn set_voters(&self, voters: Vec<Voter>) {
+    self.state.lock().unwrap().voters = voters;
+  }
+
+  /// Sets the amount of times a user has voted, as returned by [`get_vote_count`][TopggApi::get_vote_count].
+  pub fn set_vote_count(&self, user_id: u64, vote_count: u32) {
+    self
+      .state
+      .lock()
+      .unwrap()
+      .vote_counts
+      .insert(user_id, vote_count);
+  }
+
+  /// Sets whether the weekend multiplier is active, as returned by [`is_weekend`][TopggApi::is_weekend].
+  pub fn set_weekend(&self, is_weekend: bool) {
+    self.state.lock().unwrap().is_weekend = is_weekend;
+  }
+
+  /// Makes every subsequent call fail with a clone of `error` until this is called again with `None`.
+  pub fn fail_with(&self, error: Option<Error>) {
+    self.state.lock().unwrap().error = error;
+  }
+
+  /// Retrieves every [`Stats`] posted through [`post_stats`][TopggApi::post_stats] so far, from oldest to newest.
+  #[must_use]
+  #[allow(deprecated)]
+  pub fn posted_stats(&self) -> Vec<Stats> {
+    self.state.lock().unwrap().posted_stats.clone()
+  }
+
+  fn respond<T, F>(&self, f: F) -> Result<T>
+  where
+    F: FnOnce(&mut State) -> Result<T>,
+  {
+    let mut state = self.state.lock().unwrap();
+
+    match &state.error {
+      Some(err) => Err(err.clone()),
+      None => f(&mut state),
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl TopggApi for MockClient {
+  async fn get_bot(&self, id: u64) -> Result<Bot> {
+    self.respond(|state| state.bots.get(&id).cloned().ok_or(Error::NotFound))
+  }
+
+  async fn get_bot_stats(&self, id: u64) -> Result<Option<usize>> {
+    self.respond(|state| state.bot_stats.get(&id).copied().ok_or(Error::NotFound))
+  }
+
+  #[allow(deprecated)]
+  async fn get_stats(&self) -> Result<Stats> {
+    self.respond(|state| Ok(state.stats.clone()))
+  }
+
+  #[allow(deprecated)]
+  async fn post_stats(&self, new_stats: &Stats) -> Result<()> {
+    self.respond(|state| {
+      state.stats = new_stats.clone();
+      state.posted_stats.push(new_stats.clone());
+
+      Ok(())
+    })
+  }
+
+  async fn get_voters(&self) -> Result<Vec<Voter>> {
+    self.respond(|state| Ok(state.voters.clone()))
+  }
+
+  async fn get_vote_count(&self, user_id: u64) -> Result<u32> {
+    self.respond(|state| Ok(state.vote_counts.get(&user_id).copied().unwrap_or_default()))
+  }
+
+  async fn is_weekend(&self) -> Result<bool> {
+    self.respond(|state| Ok(state.is_weekend))
+  }
+}
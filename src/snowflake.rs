@@ -1,11 +1,84 @@
-use serde::{de::Error, Deserialize, Deserializer};
+use core::num::NonZeroU64;
+
+#[cfg(any(feature = "api", feature = "webhook"))]
+use {
+  core::fmt,
+  serde::{
+    de::{Error, Visitor},
+    Deserialize, Deserializer,
+  },
+};
+
+// accepts both strings and numbers, as IDs may be represented either way. invalid IDs are visited as `None`.
+#[cfg(any(feature = "api", feature = "webhook"))]
+struct SnowflakeVisitor;
+
+#[cfg(any(feature = "api", feature = "webhook"))]
+impl Visitor<'_> for SnowflakeVisitor {
+  type Value = Option<u64>;
+
+  fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("a Discord snowflake/ID as a string or a number")
+  }
+
+  #[inline(always)]
+  fn visit_u64<E: Error>(self, id: u64) -> Result<Self::Value, E> {
+    Ok(Some(id))
+  }
+
+  #[inline(always)]
+  fn visit_i64<E: Error>(self, id: i64) -> Result<Self::Value, E> {
+    Ok(u64::try_from(id).ok())
+  }
+
+  #[inline(always)]
+  fn visit_str<E: Error>(self, id: &str) -> Result<Self::Value, E> {
+    Ok(id.parse().ok())
+  }
+}
+
+#[cfg(any(feature = "api", feature = "webhook"))]
+struct MaybeSnowflake(Option<u64>);
+
+#[cfg(any(feature = "api", feature = "webhook"))]
+impl<'de> Deserialize<'de> for MaybeSnowflake {
+  #[inline(always)]
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_any(SnowflakeVisitor).map(Self)
+  }
+}
+
+#[cfg(any(feature = "api", feature = "webhook"))]
+impl MaybeSnowflake {
+  #[inline(always)]
+  fn into_result<E: Error>(self) -> Result<u64, E> {
+    self
+      .0
+      .ok_or_else(|| E::custom("invalid Discord snowflake/ID"))
+  }
+}
 
 #[inline(always)]
+#[cfg(any(feature = "api", feature = "webhook"))]
 pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
 where
   D: Deserializer<'de>,
 {
-  String::deserialize(deserializer).and_then(|s| s.parse().map_err(D::Error::custom))
+  MaybeSnowflake::deserialize(deserializer)?.into_result()
+}
+
+#[inline(always)]
+#[cfg(feature = "webhook")]
+pub(crate) fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  Option::<MaybeSnowflake>::deserialize(deserializer)?
+    .map(MaybeSnowflake::into_result)
+    .transpose()
 }
 
 #[inline(always)]
@@ -14,14 +87,80 @@ pub(crate) fn deserialize_vec<'de, D>(deserializer: D) -> Result<Vec<u64>, D::Er
 where
   D: Deserializer<'de>,
 {
-  Deserialize::deserialize(deserializer)
-    .map(|s: Vec<String>| s.into_iter().filter_map(|next| next.parse().ok()).collect())
+  Vec::<MaybeSnowflake>::deserialize(deserializer)
+    .map(|ids| ids.into_iter().filter_map(|id| id.0).collect())
 }
 
+#[inline(always)]
+#[cfg(feature = "api")]
+pub(crate) fn serialize<S>(id: &u64, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: serde::Serializer,
+{
+  serializer.collect_str(id)
+}
+
+#[inline(always)]
+#[cfg(feature = "api")]
+pub(crate) fn serialize_vec<S>(ids: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: serde::Serializer,
+{
+  serializer.collect_seq(ids.iter().map(u64::to_string))
+}
+
+/// The Discord epoch, which is the first second of 2015, in milliseconds since the Unix epoch.
+#[cfg(feature = "api")]
+pub const DISCORD_EPOCH_MS: u64 = 1_420_070_400_000;
+
+/// Retrieves the creation date of any Discord snowflake/ID, even ones that aren't fetched as a [`Bot`][crate::bot::Bot] or a [`Voter`][crate::voter::Voter].
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "api")]
+pub fn snowflake_timestamp(id: u64) -> chrono::DateTime<chrono::Utc> {
+  use chrono::TimeZone;
+
+  chrono::Utc
+    .timestamp_millis_opt(((id >> 22) + DISCORD_EPOCH_MS) as _)
+    .single()
+    .unwrap()
+}
+
+/// An error returned when a value can't be interpreted as a Discord snowflake/ID.
+#[cfg(feature = "api")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnowflakeError;
+
+#[cfg(feature = "api")]
+impl fmt::Display for SnowflakeError {
+  #[inline(always)]
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "invalid snowflake")
+  }
+}
+
+#[cfg(feature = "api")]
+impl std::error::Error for SnowflakeError {}
+
 /// A trait that represents any datatype that can be interpreted as a Discord snowflake/ID.
 pub trait Snowflake {
   /// The method that converts this value to a [`u64`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if this value can't be interpreted as a Discord snowflake/ID. See [`try_as_snowflake`][Snowflake::try_as_snowflake] for a non-panicking alternative.
   fn as_snowflake(&self) -> u64;
+
+  /// The method that converts this value to a [`u64`] without panicking.
+  ///
+  /// # Errors
+  ///
+  /// Errors if this value can't be interpreted as a Discord snowflake/ID, like a string that's not numeric.
+  #[inline(always)]
+  #[cfg(feature = "api")]
+  fn try_as_snowflake(&self) -> Result<u64, SnowflakeError> {
+    Ok(self.as_snowflake())
+  }
 }
 
 macro_rules! impl_snowflake(
@@ -37,15 +176,47 @@ macro_rules! impl_snowflake(
 );
 
 impl_snowflake!(self, u64, *self);
+impl_snowflake!(self, NonZeroU64, self.get());
 
 macro_rules! impl_string(
   ($($t:ty),+) => {$(
-    impl_snowflake!(self, $t, (*self).parse().expect("invalid snowflake as it's not numeric"));
+    impl Snowflake for $t {
+      #[inline(always)]
+      fn as_snowflake(&self) -> u64 {
+        self.parse().expect("invalid snowflake as it's not numeric")
+      }
+
+      #[inline(always)]
+      #[cfg(feature = "api")]
+      fn try_as_snowflake(&self) -> Result<u64, SnowflakeError> {
+        self.parse().map_err(|_| SnowflakeError)
+      }
+    }
   )+}
 );
 
 impl_string!(&str, String);
 
+macro_rules! impl_tryfrom(
+  ($($t:ty),+) => {$(
+    impl Snowflake for $t {
+      #[inline(always)]
+      fn as_snowflake(&self) -> u64 {
+        (**self).try_into().expect("invalid snowflake as it's out of range")
+      }
+
+      #[inline(always)]
+      #[cfg(feature = "api")]
+      fn try_as_snowflake(&self) -> Result<u64, SnowflakeError> {
+        (**self).try_into().map_err(|_| SnowflakeError)
+      }
+    }
+  )+}
+);
+
+// implemented for references only, as another integer impl would break inferring integer literals as u64.
+impl_tryfrom!(&i64);
+
 cfg_if::cfg_if! {
   if #[cfg(feature = "api")] {
     macro_rules! impl_topgg_idstruct(
@@ -66,13 +237,13 @@ cfg_if::cfg_if! {
     impl_snowflake!(
       #[cfg_attr(docsrs, doc(cfg(feature = "serenity")))] self,
       &serenity::model::guild::Member,
-      (*self).user.id.get()
+      self.user.id.get()
     );
 
     impl_snowflake!(
       #[cfg_attr(docsrs, doc(cfg(feature = "serenity")))] self,
       &serenity::model::guild::PartialMember,
-      (*self).user.as_ref().expect("user property in PartialMember is None").id.get()
+      self.user.as_ref().expect("user property in PartialMember is None").id.get()
     );
 
     macro_rules! impl_serenity_id(
@@ -82,7 +253,9 @@ cfg_if::cfg_if! {
     );
 
     impl_serenity_id!(
+      serenity::model::id::ApplicationId,
       serenity::model::id::GenericId,
+      serenity::model::id::GuildId,
       serenity::model::id::UserId
     );
 
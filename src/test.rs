@@ -1,5 +1,23 @@
-use crate::Client;
-use tokio::time::{sleep, Duration};
+use crate::{
+  bot::{Bot, BotSource, SmallWidget},
+  Client, Error,
+};
+use futures_util::{StreamExt, TryStreamExt};
+use std::{
+  net::SocketAddr,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
+};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::{TcpListener, TcpStream},
+  time::{sleep, Duration},
+};
+
+#[allow(deprecated)]
+use crate::Stats;
 
 macro_rules! delayed {
   ($($b:tt)*) => {
@@ -53,3 +71,1822 @@ async fn api() {
     let _is_weekend = client.is_weekend().await.unwrap();
   }
 }
+
+// a token whose payload resolves to the ID 264811613708746752.
+const MOCK_TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.eyJpZCI6ICIyNjQ4MTE2MTM3MDg3NDY3NTIiLCAiYm90IjogdHJ1ZX0.sig";
+
+macro_rules! mock_bot {
+  ($id:literal) => {
+    concat!(
+      r#"{"clientid":""#,
+      $id,
+      r#"","id":""#,
+      $id,
+      r#"","username":"Luca","discriminator":"0","prefix":"!","shortdesc":"A bot.","owners":["1"],"guilds":[],"date":"2017-04-26T18:08:17.125Z","points":10,"monthlyPoints":5,"support":"dbl","vanity":"luca","avatar":null}"#
+    )
+  };
+}
+
+/// A minimal keep-alive HTTP/1.1 server that answers every request through `route`.
+struct MockServer {
+  addr: SocketAddr,
+  accepts: Arc<AtomicUsize>,
+}
+
+impl MockServer {
+  async fn new(route: fn(&str, &str) -> (u16, &'static str)) -> Self {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let accepts = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&accepts);
+
+    tokio::spawn(async move {
+      while let Ok((stream, _)) = listener.accept().await {
+        counter.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(Self::serve(stream, route));
+      }
+    });
+
+    Self { addr, accepts }
+  }
+
+  async fn serve(mut stream: TcpStream, route: fn(&str, &str) -> (u16, &'static str)) {
+    let mut buffer = Vec::new();
+
+    loop {
+      let head_end = loop {
+        if let Some(pos) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+          break pos + 4;
+        }
+
+        let mut chunk = [0u8; 1024];
+
+        match stream.read(&mut chunk).await {
+          Ok(0) | Err(_) => return,
+          Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+        }
+      };
+
+      let head = String::from_utf8_lossy(&buffer[..head_end]).into_owned();
+      let mut request_line = head.split_whitespace();
+      let method = request_line.next().unwrap_or_default().to_owned();
+      let path = request_line.next().unwrap_or_default().to_owned();
+
+      let content_length = head
+        .lines()
+        .find_map(|line| {
+          let (name, value) = line.split_once(':')?;
+
+          name
+            .eq_ignore_ascii_case("content-length")
+            .then(|| value.trim().parse::<usize>().ok())?
+        })
+        .unwrap_or_default();
+
+      while buffer.len() < head_end + content_length {
+        let mut chunk = [0u8; 1024];
+
+        match stream.read(&mut chunk).await {
+          Ok(0) | Err(_) => return,
+          Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+        }
+      }
+
+      buffer.drain(..head_end + content_length);
+
+      let (status, body) = route(&method, &path);
+      let response = format!(
+        "HTTP/1.1 {status} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+        body.len()
+      );
+
+      if stream.write_all(response.as_bytes()).await.is_err() {
+        return;
+      }
+    }
+  }
+
+  fn client(&self) -> Client {
+    Client::builder(MOCK_TOKEN.to_string())
+      .base_url(format!("http://{}/api/", self.addr))
+      .build()
+  }
+}
+
+#[tokio::test]
+async fn mock_base_url() {
+  let server = MockServer::new(|method, path| match (method, path) {
+    ("GET", "/api/weekend") => (200, r#"{"is_weekend":true}"#),
+    ("GET", "/api/bots/264811613708746752") => (200, mock_bot!("264811613708746752")),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+
+  assert!(client.is_weekend().await.unwrap());
+  assert!(client.ping().await.is_ok());
+  assert!(matches!(client.get_bot(1u64).await, Err(Error::NotFound)));
+  assert_eq!(client.get_self().await.unwrap().id, 264811613708746752);
+}
+
+#[tokio::test]
+async fn mock_raw_requests() {
+  let server = MockServer::new(|method, path| match (method, path) {
+    ("GET", "/api/bots/1/reviews") => (200, r#"{"reviews":[]}"#),
+    ("POST", "/api/bots/1/reviews") => (200, ""),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+
+  assert_eq!(
+    client.get_raw("bots/1/reviews").await.unwrap(),
+    serde_json::json!({ "reviews": [] })
+  );
+  assert!(client
+    .post_raw("/bots/1/reviews", &serde_json::json!({ "rating": 5 }))
+    .await
+    .unwrap()
+    .is_null());
+  assert!(matches!(
+    client.get_raw("/unknown").await,
+    Err(Error::NotFound)
+  ));
+}
+
+#[tokio::test]
+async fn error_source() {
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url("http://127.0.0.1:1/api".to_string())
+    .build();
+
+  let err = client.is_weekend().await.unwrap_err();
+
+  assert!(matches!(err.clone(), Error::InternalClientError(_)));
+  assert!(std::error::Error::source(&err)
+    .and_then(|source| source.downcast_ref::<reqwest::Error>())
+    .is_some());
+  assert!(matches!(
+    client.ping().await,
+    Err(Error::InternalClientError(_))
+  ));
+}
+
+#[tokio::test]
+async fn mock_connection_reuse() {
+  let server = MockServer::new(|_, _| (200, r#"{"is_weekend":false}"#)).await;
+  let client = server.client();
+
+  for _ in 0..3 {
+    assert!(!client.is_weekend().await.unwrap());
+  }
+
+  assert_eq!(server.accepts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn mock_ratelimit_retry() {
+  static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+  let server = MockServer::new(|_, _| {
+    if REQUESTS.fetch_add(1, Ordering::SeqCst) == 0 {
+      (429, r#"{"retry-after":0}"#)
+    } else {
+      (200, r#"{"is_weekend":true}"#)
+    }
+  })
+  .await;
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{}/api", server.addr))
+    .retry_on_ratelimit(1)
+    .build();
+
+  assert!(client.is_weekend().await.unwrap());
+  assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn mock_ratelimit_retry_too_long() {
+  let server = MockServer::new(|_, _| (429, r#"{"retry-after":3600}"#)).await;
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{}/api", server.addr))
+    .retry_on_ratelimit(1)
+    .build();
+
+  let result = tokio::time::timeout(Duration::from_secs(5), client.is_weekend())
+    .await
+    .unwrap();
+
+  assert!(matches!(
+    result,
+    Err(Error::Ratelimit { retry_after: 3600 })
+  ));
+}
+
+#[tokio::test]
+async fn mock_validate_token() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/stats" => (401, "{}"),
+    _ => (500, "{}"),
+  })
+  .await;
+
+  assert!(!server.client().validate_token().await.unwrap());
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn mock_post_stats() {
+  let server = MockServer::new(|method, path| match (method, path) {
+    ("POST", "/api/bots/stats") => (200, "{}"),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+  let stats = Stats::from(2);
+
+  client.post_stats(&stats).await.unwrap();
+  client.post_stats(stats).await.unwrap();
+}
+
+#[tokio::test]
+async fn mock_vote_count() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/264811613708746752/check?userId=1" => (200, r#"{"voted":2}"#),
+    _ => (200, r#"{"voted":0}"#),
+  })
+  .await;
+
+  let client = server.client();
+
+  assert_eq!(client.get_vote_count(1).await.unwrap(), 2);
+  assert!(client.has_voted(1).await.unwrap());
+  assert!(!client.has_voted(2).await.unwrap());
+
+  let voted = client.has_voted_many([1, 2], 2).await;
+
+  assert_eq!(voted.len(), 2);
+  assert!(matches!(voted[0], (1, Ok(true))));
+  assert!(matches!(voted[1], (2, Ok(false))));
+
+  let voters = client.voters_among([1, 2, 1], 2).await.unwrap();
+
+  assert_eq!(voters, [1].into());
+}
+
+#[tokio::test]
+async fn mock_get_bots_by_ids() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/1" => (200, mock_bot!("1")),
+    "/api/bots/3" => (200, mock_bot!("3")),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let bots = server.client().get_bots_by_ids([1, 2, 3], 2).await;
+
+  assert_eq!(bots.len(), 3);
+  assert_eq!(bots[0].as_ref().unwrap().id, 1);
+  assert!(matches!(bots[1], Err(Error::NotFound)));
+  assert_eq!(bots[2].as_ref().unwrap().id, 3);
+}
+
+#[tokio::test]
+async fn mock_http_accessor() {
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  let client = server.client();
+
+  let res = client
+    .http()
+    .get(format!("http://{}/", server.addr))
+    .send()
+    .await
+    .unwrap();
+
+  assert_eq!(res.status(), 200);
+
+  // the accessor shares the client's connection pool.
+  client.get_stats().await.unwrap();
+
+  assert_eq!(server.accepts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn mock_get_bot_stats() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/1/stats" => (200, r#"{"server_count":42,"shards":[]}"#),
+    "/api/bots/2/stats" => (200, r#"{"shards":[]}"#),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+
+  assert_eq!(client.get_bot_stats(1).await.unwrap(), Some(42));
+  assert_eq!(client.get_bot_stats(2).await.unwrap(), None);
+  assert!(matches!(
+    client.get_bot_stats(3).await,
+    Err(Error::NotFound)
+  ));
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn mock_retry_transient() {
+  let client = |retries: u8| async move {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+      // the first connection never gets a response, timing out the first attempt.
+      let (_stalled, _) = listener.accept().await.unwrap();
+
+      while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(MockServer::serve(stream, |_, _| (200, "{}")));
+      }
+    });
+
+    Client::builder(MOCK_TOKEN.to_string())
+      .base_url(format!("http://{addr}/api"))
+      .timeout(Duration::from_millis(200))
+      .retry_transient(retries)
+      .build()
+  };
+
+  assert!(matches!(
+    client(0).await.get_stats().await,
+    Err(Error::InternalClientError(err)) if err.is_timeout()
+  ));
+  assert!(client(1).await.get_stats().await.is_ok());
+
+  // non-idempotent requests are never retried.
+  assert!(client(1).await.post_stats(Stats::from(1)).await.is_err());
+}
+
+#[tokio::test]
+async fn mock_truncated_body() {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  tokio::spawn(async move {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut chunk = [0u8; 1024];
+
+    assert_ne!(stream.read(&mut chunk).await.unwrap(), 0);
+
+    // the connection is closed before the promised body is fully sent.
+    stream
+      .write_all(b"HTTP/1.1 200 Mock\r\ncontent-length: 64\r\n\r\n{}")
+      .await
+      .unwrap();
+  });
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{addr}/api"))
+    .build();
+
+  assert!(matches!(
+    client.get_bot(1).await,
+    Err(Error::InternalClientError(err)) if err.is_body() || err.is_decode()
+  ));
+}
+
+#[tokio::test]
+async fn mock_default_headers() {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  let head = tokio::spawn(async move {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut buffer = Vec::new();
+
+    while !buffer.windows(4).any(|w| w == b"\r\n\r\n") {
+      let mut chunk = [0u8; 1024];
+      let n = stream.read(&mut chunk).await.unwrap();
+
+      buffer.extend_from_slice(&chunk[..n]);
+    }
+
+    stream
+      .write_all(b"HTTP/1.1 200 Mock\r\ncontent-length: 2\r\n\r\n{}")
+      .await
+      .unwrap();
+
+    String::from_utf8(buffer).unwrap().to_lowercase()
+  });
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{addr}/api"))
+    .default_header("x-route", "a")
+    .default_header("x-route", "b")
+    .default_header("authorization", "clobbered")
+    .build();
+
+  client.get_stats().await.unwrap();
+
+  let head = head.await.unwrap();
+
+  assert!(head.contains("x-route: a\r\n"));
+  assert!(head.contains("x-route: b\r\n"));
+  assert!(head.contains(&format!("authorization: {}\r\n", MOCK_TOKEN.to_lowercase())));
+  assert!(!head.contains("clobbered"));
+}
+
+#[tokio::test]
+async fn mock_ratelimit_info() {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  tokio::spawn(async move {
+    let (mut stream, _) = listener.accept().await.unwrap();
+    let mut chunk = [0u8; 1024];
+
+    for remaining in [59, 58] {
+      assert_ne!(stream.read(&mut chunk).await.unwrap(), 0);
+      stream
+        .write_all(
+          format!(
+            "HTTP/1.1 200 Mock\r\nx-ratelimit-limit: 60\r\nx-ratelimit-remaining: {remaining}\r\nx-ratelimit-reset: 1.5\r\ncontent-length: 2\r\n\r\n{{}}"
+          )
+          .as_bytes(),
+        )
+        .await
+        .unwrap();
+    }
+  });
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{addr}/api"))
+    .build();
+
+  assert!(client.last_ratelimit_info().is_none());
+
+  client.get_stats().await.unwrap();
+  client.get_stats().await.unwrap();
+
+  assert_eq!(
+    client.last_ratelimit_info(),
+    Some(crate::RateLimitInfo {
+      limit: 60,
+      remaining: 58,
+      reset: Duration::from_millis(1500),
+    })
+  );
+}
+
+#[test]
+#[cfg(feature = "blocking")]
+fn mock_blocking_client() {
+  use std::{sync::mpsc, thread};
+
+  let (tx, rx) = mpsc::channel();
+
+  // the mock server runs on its own runtime, as the blocking client can't be used from within one.
+  thread::spawn(move || {
+    tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .unwrap()
+      .block_on(async move {
+        let server = MockServer::new(|_, path| match path {
+          "/api/bots/1" => (200, mock_bot!("1")),
+          "/api/bots?limit=1" => (200, concat!(r#"{"results":["#, mock_bot!("2"), "]}")),
+          "/api/bots/264811613708746752/check?userId=3" => (200, r#"{"voted":1}"#),
+          _ => (404, "{}"),
+        })
+        .await;
+
+        tx.send(server.client()).unwrap();
+
+        std::future::pending::<()>().await
+      })
+  });
+
+  let client = crate::blocking::Client::from(rx.recv().unwrap());
+
+  assert_eq!(client.get_bot(1).unwrap().id, 1);
+  assert!(client.try_get_bot(4).unwrap().is_none());
+  assert_eq!(client.get_bots(|query| query.limit(1)).unwrap()[0].id, 2);
+  assert!(client.has_voted(3).unwrap());
+}
+
+#[tokio::test]
+#[cfg(feature = "mock")]
+#[allow(deprecated)]
+async fn mock_topgg_api() {
+  use crate::{mock::MockClient, TopggApi};
+
+  async fn has_voted(api: &dyn TopggApi, user_id: u64) -> bool {
+    api.has_voted(user_id).await.unwrap()
+  }
+
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/264811613708746752/check?userId=1" => (200, r#"{"voted":1}"#),
+    _ => (200, r#"{"voted":0}"#),
+  })
+  .await;
+
+  assert!(has_voted(&server.client(), 1).await);
+  assert!(!has_voted(&server.client(), 2).await);
+
+  let mock = MockClient::new();
+
+  mock.set_vote_count(1, 2);
+  mock.add_bot(serde_json::from_str(mock_bot!("3")).unwrap());
+
+  assert!(has_voted(&mock, 1).await);
+  assert!(!has_voted(&mock, 2).await);
+  assert_eq!(mock.get_bot(3).await.unwrap().id, 3);
+  assert!(matches!(mock.get_bot(4).await, Err(Error::NotFound)));
+
+  mock.post_stats(&Stats::from(5)).await.unwrap();
+
+  assert_eq!(mock.get_stats().await.unwrap().server_count(), Some(5));
+  assert_eq!(mock.posted_stats(), [Stats::from(5)]);
+
+  mock.fail_with(Some(Error::Unauthorized));
+
+  assert!(matches!(mock.is_weekend().await, Err(Error::Unauthorized)));
+}
+
+#[tokio::test]
+async fn mock_max_concurrent() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/1" => (200, mock_bot!("1")),
+    "/api/bots/2" => (200, mock_bot!("2")),
+    _ => (200, mock_bot!("3")),
+  })
+  .await;
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{}/api", server.addr))
+    .max_concurrent(1)
+    .build();
+
+  let bots = client.get_bots_by_ids([1, 2, 3], 3).await;
+
+  assert!(bots.iter().all(Result::is_ok));
+
+  // requests never overlap, so they all reuse the same connection.
+  assert_eq!(server.accepts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn mock_internal_server_error_body() {
+  let server = MockServer::new(|_, _| (500, r#"{"error":"something went wrong"}"#)).await;
+
+  match server.client().get_bot(1).await {
+    Err(Error::InternalServerError { status, body }) => {
+      assert_eq!(status, 500);
+      assert_eq!(body, r#"{"error":"something went wrong"}"#);
+    }
+    other => panic!("unexpected result: {other:?}"),
+  }
+}
+
+#[tokio::test]
+async fn mock_deserialize_error() {
+  use std::error::Error as _;
+
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/1" => (200, r#"{"clientid":"1","username":"a","points":"many"}"#),
+    "/api/bots/2" => (200, "{not json"),
+    "/api/bots/4" => (200, concat!(mock_bot!("4"), "{}")),
+    "/api/bots/264811613708746752/votes?page=1" => (200, r#"[{"id":"1","username":1}]"#),
+    _ => (429, r#"{"retry_after":"soon"}"#),
+  })
+  .await;
+  let client = server.client();
+
+  match client.get_bot(1).await {
+    Err(err @ Error::Deserialize { .. }) => {
+      assert!(matches!(&err, Error::Deserialize { path, .. } if path == "points"));
+      assert!(err.source().is_some());
+      assert!(err
+        .to_string()
+        .starts_with("failed to deserialize the response at points: "));
+    }
+    other => panic!("unexpected result: {other:?}"),
+  }
+
+  assert!(matches!(
+    client.get_bot(2).await,
+    Err(Error::Deserialize { .. })
+  ));
+  assert!(matches!(
+    client.get_bot(4).await,
+    Err(Error::Deserialize { path, .. }) if path == "."
+  ));
+
+  // error responses are still the server's fault.
+  assert!(matches!(
+    client.get_bot(3).await,
+    Err(Error::InternalServerError { status: 429, .. })
+  ));
+
+  let voters: Vec<_> = client.voters_page_stream(0).collect().await;
+
+  assert!(matches!(&voters[..], [Err(Error::Deserialize { path, .. })] if path == "username"));
+}
+
+#[tokio::test]
+async fn mock_invalid_id() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/1" => (200, mock_bot!("1")),
+    _ => (404, "{}"),
+  })
+  .await;
+  let client = server.client();
+
+  assert!(matches!(
+    client.try_get_bot("not an id").await,
+    Err(Error::InvalidId)
+  ));
+  assert!(matches!(
+    client.try_has_voted(&-1i64).await,
+    Err(Error::InvalidId)
+  ));
+  assert_eq!(client.try_get_bot("1").await.unwrap().unwrap().id, 1);
+  assert!(client.try_get_bot(2).await.unwrap().is_none());
+
+  // invalid IDs are rejected before any request is sent.
+  assert_eq!(server.accepts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn mock_voters_page_stream() {
+  use crate::voter::VoterRef;
+  use std::borrow::Cow;
+
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots/264811613708746752/votes?page=1" => (
+      200,
+      r#" [ {"id":"1","username":"[a]","avatar":null} , {"id":"2","username":"b\"},{","avatar":null}]"#,
+    ),
+    _ => (200, r#"[{"id":"1","username":"a","avatar":null},{"id":"2""#),
+  })
+  .await;
+
+  let client = server.client();
+  let voters: Vec<_> = client.voters_page_stream(0).try_collect().await.unwrap();
+
+  assert_eq!(voters.iter().map(|v| v.id).collect::<Vec<_>>(), [1, 2]);
+  assert_eq!(voters[0].username, "[a]");
+  assert_eq!(voters[1].username, "b\"},{");
+
+  let voters: Vec<_> = client.voters_page_stream(2).collect().await;
+
+  assert_eq!(voters.len(), 2);
+  assert_eq!(voters[0].as_ref().unwrap().id, 1);
+  assert!(matches!(voters[1], Err(Error::Deserialize { .. })));
+
+  let body = client.get_voters_page_raw(1).await.unwrap();
+  let voters: Vec<VoterRef<'_>> = serde_json::from_slice(&body).unwrap();
+
+  assert!(matches!(voters[0].username, Cow::Borrowed("[a]")));
+  assert!(matches!(&voters[1].username, Cow::Owned(username) if username == "b\"},{"));
+  assert_eq!(voters[1].clone().into_owned().id, 2);
+}
+
+#[test]
+fn transient_retry_delay() {
+  use crate::client::transient_retry_delay;
+
+  assert_eq!(transient_retry_delay(0), Duration::from_millis(250));
+  assert_eq!(transient_retry_delay(2), Duration::from_secs(1));
+  assert_eq!(transient_retry_delay(7), Duration::from_secs(30));
+  assert_eq!(transient_retry_delay(u8::MAX), Duration::from_secs(30));
+}
+
+#[test]
+fn get_bots_effective_values() {
+  use crate::bot::{DEFAULT_LIMIT, MAX_LIMIT, MAX_SKIP};
+
+  let client = Client::new(MOCK_TOKEN.to_string());
+  let query = client.get_bots();
+
+  assert_eq!(query.effective_limit(), DEFAULT_LIMIT);
+  assert_eq!(query.effective_skip(), 0);
+
+  let query = query.limit(1000).skip(1000);
+
+  assert_eq!(query.effective_limit(), MAX_LIMIT);
+  assert_eq!(query.effective_skip(), MAX_SKIP);
+
+  let query = query.limit(0);
+
+  assert_eq!(query.effective_limit(), 1);
+
+  let query = query.limit(10).skip(20);
+
+  assert_eq!(query.effective_limit(), 10);
+  assert_eq!(query.effective_skip(), 20);
+}
+
+#[tokio::test]
+async fn mock_get_bots_cancellation() {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  tokio::spawn(async move {
+    // the first connection never gets a response, so its request is still in-flight when dropped.
+    let (_stalled, _) = listener.accept().await.unwrap();
+
+    while let Ok((stream, _)) = listener.accept().await {
+      tokio::spawn(MockServer::serve(stream, |_, _| {
+        (200, concat!(r#"{"results":["#, mock_bot!("1"), "]}"))
+      }));
+    }
+  });
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{addr}/api"))
+    .max_concurrent(1)
+    .build();
+
+  tokio::select! {
+    _ = client.get_bots().limit(1) => panic!("the stalled request completed"),
+    _ = sleep(Duration::from_millis(100)) => {},
+  }
+
+  // the dropped request released its concurrency slot.
+  let bots = tokio::time::timeout(Duration::from_secs(5), client.get_bots().limit(1))
+    .await
+    .unwrap()
+    .unwrap();
+
+  assert_eq!(bots[0].id, 1);
+}
+
+#[tokio::test]
+async fn mock_get_bots_summaries() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots?fields=clientid,id,username,avatar" => (
+      200,
+      r#"{"results":[{"clientid":"1","id":"2","username":"Luca","avatar":null}]}"#,
+    ),
+    "/api/bots?limit=1&search=username%3A%20Luca%20&fields=clientid,id,username,avatar" => {
+      (200, concat!(r#"{"results":["#, mock_bot!("3"), "]}"))
+    }
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+  let summaries = client.get_bots().summaries().await.unwrap();
+
+  assert_eq!(summaries[0].id, 1);
+  assert_eq!(summaries[0].topgg_id, 2);
+  assert_eq!(summaries[0].username, "Luca");
+
+  // full bot objects are still accepted.
+  let summaries = client
+    .get_bots()
+    .limit(1)
+    .username("Luca")
+    .summaries()
+    .await
+    .unwrap();
+
+  assert_eq!(summaries[0].id, 3);
+}
+
+#[tokio::test]
+async fn mock_get_bots_votes_range() {
+  let server = MockServer::new(|_, _| {
+    (
+      200,
+      concat!(
+        r#"{"results":["#,
+        mock_bot!("1"),
+        ",",
+        mock_bot!("2"),
+        r#"],"total":2}"#
+      ),
+    )
+  })
+  .await;
+
+  let client = server.client();
+
+  assert_eq!(
+    client
+      .get_bots()
+      .min_votes(10)
+      .max_votes(10)
+      .await
+      .unwrap()
+      .len(),
+    2
+  );
+  assert!(client.get_bots().min_votes(11).await.unwrap().is_empty());
+
+  let (bots, total) = client.get_bots().max_votes(9).with_total().await.unwrap();
+
+  assert!(bots.is_empty());
+  assert_eq!(total, 2);
+}
+
+#[tokio::test]
+async fn mock_voters_stream() {
+  static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+  let server = MockServer::new(|_, path| {
+    REQUESTS.fetch_add(1, Ordering::SeqCst);
+
+    match path {
+      "/api/bots/264811613708746752/votes?page=1" => (
+        200,
+        r#"[{"id":"1","username":"a","avatar":null},{"id":"2","username":"b","avatar":null}]"#,
+      ),
+      _ => (200, "[]"),
+    }
+  })
+  .await;
+
+  let voters: Vec<_> = server.client().voters_stream().try_collect().await.unwrap();
+
+  assert_eq!(voters.iter().map(|v| v.id).collect::<Vec<_>>(), [1, 2]);
+  assert_eq!(REQUESTS.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn mock_get_bots_stream() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots?limit=2&offset=0" | "/api/bots?limit=2" => (
+      200,
+      concat!(r#"{"results":["#, mock_bot!("1"), ",", mock_bot!("2"), "]}"),
+    ),
+    "/api/bots?limit=2&offset=2" => (200, concat!(r#"{"results":["#, mock_bot!("3"), "]}")),
+    _ => (500, "{}"),
+  })
+  .await;
+
+  let client = server.client();
+  let bots: Vec<_> = client
+    .get_bots()
+    .limit(2)
+    .stream()
+    .try_collect()
+    .await
+    .unwrap();
+
+  assert_eq!(bots.iter().map(|b| b.id).collect::<Vec<_>>(), [1, 2, 3]);
+  assert!(bots.iter().all(|b| b.source() == BotSource::Search));
+
+  let mut ids = Vec::new();
+  let mut page = client.get_bots().limit(2).page().await.unwrap();
+
+  loop {
+    ids.extend(page.bots().iter().map(|b| b.id));
+
+    match page.next().await.unwrap() {
+      Some(next) => page = next,
+      None => break,
+    }
+  }
+
+  assert_eq!(ids, [1, 2, 3]);
+}
+
+#[tokio::test]
+async fn mock_get_bots_zero_limit() {
+  let server = MockServer::new(|_, path| match path {
+    "/api/bots?limit=1&offset=0" | "/api/bots?limit=1" => {
+      (200, concat!(r#"{"results":["#, mock_bot!("1"), "]}"))
+    }
+    "/api/bots?limit=1&offset=1" => (200, concat!(r#"{"results":["#, mock_bot!("2"), "]}")),
+    "/api/bots?limit=1&offset=2" => (200, concat!(r#"{"results":["#, mock_bot!("3"), "]}")),
+    "/api/bots?limit=1&offset=3" => (200, r#"{"results":[]}"#),
+    _ => (
+      200,
+      concat!(
+        r#"{"results":["#,
+        mock_bot!("1"),
+        ",",
+        mock_bot!("2"),
+        ",",
+        mock_bot!("3"),
+        "]}"
+      ),
+    ),
+  })
+  .await;
+
+  let client = server.client();
+  let bots: Vec<_> = client
+    .get_bots()
+    .limit(0)
+    .stream()
+    .try_collect()
+    .await
+    .unwrap();
+
+  assert_eq!(bots.iter().map(|b| b.id).collect::<Vec<_>>(), [1, 2, 3]);
+
+  let mut ids = Vec::new();
+  let mut page = client.get_bots().limit(0).page().await.unwrap();
+
+  loop {
+    ids.extend(page.bots().iter().map(|b| b.id));
+
+    assert!(ids.len() <= 3, "paginated past the last bot");
+
+    match page.next().await.unwrap() {
+      Some(next) => page = next,
+      None => break,
+    }
+  }
+
+  assert_eq!(ids, [1, 2, 3]);
+}
+
+#[test]
+fn bot_numeric_snowflakes() {
+  let mut bot: serde_json::Value = serde_json::from_str(mock_bot!("1")).unwrap();
+
+  bot["clientid"] = serde_json::json!(264811613708746752u64);
+  bot["owners"] = serde_json::json!([1, "2", "invalid", -3]);
+
+  let bot: Bot = serde_json::from_value(bot).unwrap();
+
+  assert_eq!(bot.id, 264811613708746752);
+  assert_eq!(bot.owners, [1, 2]);
+
+  let mut bot = serde_json::to_value(&bot).unwrap();
+
+  bot["clientid"] = serde_json::json!(-1);
+
+  assert!(serde_json::from_value::<Bot>(bot).is_err());
+}
+
+#[test]
+fn bot_serialize_round_trip() {
+  let bot: Bot = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+  let round_tripped: Bot = serde_json::from_str(&serde_json::to_string(&bot).unwrap()).unwrap();
+
+  assert_eq!(
+    serde_json::to_value(&bot).unwrap(),
+    serde_json::to_value(&round_tripped).unwrap()
+  );
+
+  assert_eq!(round_tripped.id, 264811613708746752);
+  assert_eq!(round_tripped.owners, [1]);
+  assert_eq!(
+    round_tripped.support.as_deref(),
+    Some("https://discord.com/invite/dbl")
+  );
+  assert_eq!(round_tripped.url(), "https://top.gg/bot/luca");
+  assert_eq!(round_tripped.source(), BotSource::Fetched);
+}
+
+#[test]
+fn voter_serialize_round_trip() {
+  use crate::voter::Voter;
+
+  let voter: Voter = serde_json::from_str(
+    r#"{"id":"661200758510977084","username":"null","avatar":"a_1241439d430def25c100dd28add2d42f"}"#,
+  )
+  .unwrap();
+  let round_tripped: Voter = serde_json::from_str(&serde_json::to_string(&voter).unwrap()).unwrap();
+
+  assert_eq!(voter, round_tripped);
+  assert_eq!(round_tripped.id, 661200758510977084);
+  assert!(round_tripped.is_animated_avatar());
+}
+
+#[test]
+fn bot_lenient_approval_date() {
+  let mut bot: serde_json::Value = serde_json::from_str(mock_bot!("1")).unwrap();
+
+  assert!(serde_json::from_value::<Bot>(bot.clone())
+    .unwrap()
+    .approved_at
+    .is_some());
+
+  for date in [serde_json::Value::Null, "not a date".into(), 0.into()] {
+    bot["date"] = date;
+
+    assert!(serde_json::from_value::<Bot>(bot.clone())
+      .unwrap()
+      .approved_at
+      .is_none());
+  }
+
+  bot.as_object_mut().unwrap().remove("date");
+
+  assert!(serde_json::from_value::<Bot>(bot)
+    .unwrap()
+    .approved_at
+    .is_none());
+}
+
+#[test]
+fn bot_extra_fields() {
+  let mut bot: serde_json::Value = serde_json::from_str(mock_bot!("1")).unwrap();
+  bot["brandNewField"] = true.into();
+
+  let bot: Bot = serde_json::from_value(bot).unwrap();
+
+  assert_eq!(bot.extra["brandNewField"], true);
+  assert!(!bot.extra.contains_key("clientid"));
+  assert_eq!(serde_json::to_value(&bot).unwrap()["brandNewField"], true);
+}
+
+#[test]
+#[allow(deprecated)]
+fn stats_eq() {
+  use std::collections::HashSet;
+
+  assert_eq!(Stats::from(1), Stats::from(1));
+  assert_ne!(Stats::from(1), Stats::from(2));
+  assert_eq!(
+    [Stats::from(1), Stats::from(1)]
+      .into_iter()
+      .collect::<HashSet<_>>()
+      .len(),
+    1
+  );
+}
+
+#[test]
+#[allow(deprecated)]
+fn stats_optional_server_count() {
+  let mut stats = Stats::from(None);
+
+  assert_eq!(serde_json::to_string(&stats).unwrap(), "{}");
+
+  stats.set_server_count(Some(2));
+
+  assert_eq!(stats, Stats::from(Some(2)));
+  assert_eq!(
+    serde_json::to_string(&stats).unwrap(),
+    r#"{"server_count":2}"#
+  );
+}
+
+#[test]
+fn snowflake_timestamp() {
+  use crate::{snowflake_timestamp, DISCORD_EPOCH_MS};
+
+  assert_eq!(
+    snowflake_timestamp(0).timestamp_millis() as u64,
+    DISCORD_EPOCH_MS
+  );
+  assert_eq!(
+    snowflake_timestamp(264811613708746752).timestamp_millis(),
+    1_483_206_408_670
+  );
+
+  let bot: Bot = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+
+  assert_eq!(bot.created_at(), snowflake_timestamp(bot.id));
+}
+
+#[test]
+fn bot_voter_eq() {
+  use crate::voter::Voter;
+  use std::collections::HashSet;
+
+  let bot: Bot = serde_json::from_str(mock_bot!("1")).unwrap();
+
+  assert!(bot == bot.clone());
+  assert!(bot != serde_json::from_str(mock_bot!("2")).unwrap());
+
+  let mut searched = bot.clone();
+
+  searched.source = BotSource::Search;
+
+  assert!(bot == searched);
+
+  let voters: Vec<Voter> = serde_json::from_str(
+    r#"[{"id":"1","username":"a","avatar":null},{"id":"1","username":"a","avatar":null},{"id":"2","username":"b","avatar":null}]"#,
+  )
+  .unwrap();
+
+  assert_eq!(voters.into_iter().collect::<HashSet<_>>().len(), 2);
+}
+
+#[test]
+fn vote_url() {
+  assert_eq!(
+    crate::bot::vote_url(264811613708746752),
+    "https://top.gg/bot/264811613708746752/vote"
+  );
+  assert_eq!(
+    crate::bot::vote_url("264811613708746752"),
+    "https://top.gg/bot/264811613708746752/vote"
+  );
+
+  let mut bot: serde_json::Value = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+
+  assert_eq!(
+    serde_json::from_value::<Bot>(bot.clone())
+      .unwrap()
+      .vote_url(),
+    "https://top.gg/bot/luca/vote"
+  );
+
+  bot["vanity"] = serde_json::Value::Null;
+
+  let bot: Bot = serde_json::from_value(bot).unwrap();
+
+  assert_eq!(bot.vote_url(), "https://top.gg/bot/264811613708746752/vote");
+  assert_eq!(bot.vote_count_this_month(), bot.monthly_votes);
+}
+
+#[test]
+fn widget_url() {
+  let bot: Bot = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+
+  assert_eq!(
+    bot.widget_url(),
+    "https://top.gg/api/widget/264811613708746752.svg"
+  );
+  assert_eq!(
+    bot.small_widget_url(SmallWidget::Servers),
+    "https://top.gg/api/widget/servers/264811613708746752.svg"
+  );
+}
+
+#[test]
+fn avatar_with_size() {
+  let mut bot: serde_json::Value = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+  bot["avatar"] = "a_hash".into();
+
+  let bot: Bot = serde_json::from_value(bot).unwrap();
+  let url = "https://cdn.discordapp.com/avatars/264811613708746752/a_hash.gif?size=";
+
+  assert_eq!(bot.avatar(), format!("{url}1024"));
+  assert_eq!(bot.avatar_with_size(256), format!("{url}256"));
+  assert_eq!(bot.avatar_with_size(200), format!("{url}256"));
+  assert_eq!(bot.avatar_with_size(150), format!("{url}128"));
+  assert_eq!(bot.avatar_with_size(0), format!("{url}16"));
+  assert_eq!(bot.avatar_with_size(u16::MAX), format!("{url}4096"));
+  assert_eq!(bot.avatar_hash(), Some("a_hash"));
+  assert!(bot.is_animated_avatar());
+
+  let bot: Bot = serde_json::from_str(mock_bot!("264811613708746752")).unwrap();
+
+  assert!(!bot.is_animated_avatar());
+}
+
+#[test]
+#[cfg(feature = "webhook")]
+fn vote_deserialize() {
+  use crate::{Vote, VoteKind, VoteTarget};
+
+  let vote: Vote = serde_json::from_str(
+    r#"{"bot":"1","user":"2","type":"test","isWeekend":true,"query":"a=b%20c&d%3De=f=g"}"#,
+  )
+  .unwrap();
+
+  assert_eq!(vote.receiver_id, 1);
+  assert_eq!(vote.voter_id, 2);
+  assert!(!vote.is_server);
+  assert_eq!(vote.target(), VoteTarget::Bot(1));
+  assert_eq!(vote.kind(), VoteKind::Test);
+  assert!(vote.is_weekend);
+  assert_eq!(vote.query["a"], "b c");
+  assert_eq!(vote.query["d=e"], "f=g");
+
+  let vote: Vote = serde_json::from_str(
+    r#"{"bot":"1","user":"2","type":"upvote","query":"?campaign=spring+sale&flag&&campaign=summer%20sale"}"#,
+  )
+  .unwrap();
+
+  assert_eq!(vote.query_param("campaign"), Some("summer sale"));
+  assert_eq!(vote.query_param("flag"), Some(""));
+  assert_eq!(vote.query_param("missing"), None);
+  assert_eq!(vote.query.len(), 2);
+
+  let vote: Vote =
+    serde_json::from_str(r#"{"bot":"1","user":"2","type":"upvote","query":""}"#).unwrap();
+
+  assert!(vote.query.is_empty());
+
+  let vote: Vote = serde_json::from_str(r#"{"guild":"1","user":"2","type":"upvote"}"#).unwrap();
+
+  assert!(vote.is_server);
+  assert_eq!(vote.target(), VoteTarget::Guild(1));
+  assert_eq!(vote.kind(), VoteKind::Upvote);
+  assert!(vote.query.is_empty());
+
+  let err = serde_json::from_str::<Vote>(r#"{"user":"2","type":"upvote"}"#).unwrap_err();
+
+  assert!(err.to_string().contains("missing field `bot` or `guild`"));
+}
+
+#[test]
+#[cfg(feature = "webhook")]
+fn verify_authorization() {
+  assert!(crate::verify_authorization("password", "password"));
+  assert!(!crate::verify_authorization("password", "passwore"));
+  assert!(!crate::verify_authorization("password", "password2"));
+  assert!(!crate::verify_authorization("password", ""));
+  assert!(!crate::verify_authorization("password", "pass"));
+  assert!(!crate::verify_authorization("pass", "pass\0\0\0\0"));
+  assert!(!crate::verify_authorization("", "\0"));
+  assert!(crate::verify_authorization("", ""));
+}
+
+#[test]
+#[cfg(feature = "webhook")]
+fn vote_deduplicator() {
+  use crate::{Vote, VoteDeduplicator};
+
+  let vote = |user: u64| -> Vote {
+    serde_json::from_str(&format!(r#"{{"bot":"1","user":"{user}","type":"upvote"}}"#)).unwrap()
+  };
+
+  let deduplicator = VoteDeduplicator::new(Duration::from_secs(60), 2);
+
+  assert!(!deduplicator.is_duplicate(&vote(1)));
+  assert!(deduplicator.is_duplicate(&vote(1)));
+  assert!(!deduplicator.is_duplicate(&vote(2)));
+  assert!(!deduplicator.is_duplicate(&vote(3)));
+
+  // the oldest vote is forgotten once the capacity is exceeded.
+  assert!(!deduplicator.is_duplicate(&vote(1)));
+
+  deduplicator.clear();
+  assert!(!deduplicator.is_duplicate(&vote(3)));
+
+  let deduplicator = VoteDeduplicator::new(Duration::from_millis(10), 2);
+
+  assert!(!deduplicator.is_duplicate(&vote(1)));
+  std::thread::sleep(Duration::from_millis(20));
+  assert!(!deduplicator.is_duplicate(&vote(1)));
+}
+
+#[tokio::test]
+#[cfg(feature = "webhook")]
+async fn vote_dispatcher() {
+  use crate::{Vote, VoteDispatcher};
+  use tokio::sync::mpsc;
+
+  let (tx, mut rx) = mpsc::unbounded_channel();
+  let dispatcher = VoteDispatcher::new("password", move |vote: Vote| {
+    let tx = tx.clone();
+
+    async move {
+      tx.send(vote.voter_id).unwrap();
+    }
+  });
+
+  let body = br#"{"bot":"1","user":"2","type":"upvote"}"#;
+
+  assert_eq!(dispatcher.dispatch(Some("password"), body).await, 200);
+  assert_eq!(rx.recv().await, Some(2));
+  assert_eq!(dispatcher.dispatch(Some("wrong"), body).await, 401);
+  assert_eq!(dispatcher.dispatch(None, body).await, 401);
+  assert_eq!(dispatcher.dispatch(Some("password"), b"{}").await, 400);
+  assert!(rx.try_recv().is_err());
+}
+
+#[tokio::test]
+#[cfg(feature = "axum")]
+async fn axum_vote_extractor() {
+  use crate::{axum::TopggVote, WebhookSecret};
+  use axum::{routing::post, Router};
+
+  let secret = WebhookSecret::new("password");
+  let app = Router::new()
+    .route(
+      "/webhook",
+      post(|vote: TopggVote| async move { vote.voter_id.to_string() }),
+    )
+    .with_state(secret.clone());
+
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let url = format!("http://{}/webhook", listener.local_addr().unwrap());
+
+  tokio::spawn(async move { axum::serve(listener, app).await });
+
+  let http = reqwest::Client::new();
+  let send = |authorization: &'static str, body: &'static str| {
+    http
+      .post(&url)
+      .header("Authorization", authorization)
+      .body(body)
+      .send()
+  };
+
+  let res = send("password", r#"{"bot":"1","user":"2","type":"upvote"}"#)
+    .await
+    .unwrap();
+
+  assert_eq!(res.status(), 200);
+  assert_eq!(res.text().await.unwrap(), "2");
+
+  let res = send("wrong", r#"{"bot":"1","user":"2","type":"upvote"}"#)
+    .await
+    .unwrap();
+
+  assert_eq!(res.status(), 401);
+  assert_eq!(send("password", "{}").await.unwrap().status(), 400);
+
+  secret.rotate("rotated");
+
+  let vote = r#"{"bot":"1","user":"2","type":"upvote"}"#;
+
+  assert_eq!(send("password", vote).await.unwrap().status(), 401);
+  assert_eq!(send("rotated", vote).await.unwrap().status(), 200);
+}
+
+#[tokio::test]
+#[cfg(feature = "warp")]
+async fn warp_vote_filter() {
+  use crate::warp::{filter, Unauthorized};
+
+  let filter = filter("password");
+  let request = |authorization: &str| {
+    warp::test::request()
+      .method("POST")
+      .header("Authorization", authorization)
+      .body(r#"{"bot":"1","user":"2","type":"upvote"}"#)
+  };
+
+  let vote = request("password").filter(&filter).await.unwrap();
+
+  assert_eq!(vote.voter_id, 2);
+
+  let rejection = request("wrong").filter(&filter).await.unwrap_err();
+
+  assert!(rejection.find::<Unauthorized>().is_some());
+
+  let rejection = request("password")
+    .body(vec![b' '; 4097])
+    .filter(&filter)
+    .await
+    .unwrap_err();
+
+  assert!(rejection.find::<warp::reject::PayloadTooLarge>().is_some());
+}
+
+#[tokio::test]
+#[cfg(feature = "actix-web")]
+async fn actix_web_vote_extractor() {
+  use crate::{actix_web::TopggVote, WebhookSecret};
+  use actix_web::{http::StatusCode, test::TestRequest, web::Data, FromRequest};
+
+  let extract = |authorization: &str, body: &'static str| {
+    let (req, mut payload) = TestRequest::post()
+      .insert_header(("Authorization", authorization))
+      .insert_header(("Content-Type", "application/json"))
+      .app_data(Data::new(WebhookSecret::new("password")))
+      .set_payload(body)
+      .to_http_parts();
+
+    TopggVote::from_request(&req, &mut payload)
+  };
+
+  let vote = r#"{"bot":"1","user":"2","type":"upvote"}"#;
+  let status = |err: actix_web::Error| err.as_response_error().status_code();
+
+  assert_eq!(extract("password", vote).await.unwrap().voter_id, 2);
+  assert_eq!(
+    status(extract("wrong", vote).await.unwrap_err()),
+    StatusCode::UNAUTHORIZED
+  );
+  assert_eq!(
+    status(extract("wrong", "{}").await.unwrap_err()),
+    StatusCode::UNAUTHORIZED
+  );
+  assert_eq!(
+    status(extract("password", "{}").await.unwrap_err()),
+    StatusCode::BAD_REQUEST
+  );
+}
+
+#[tokio::test]
+#[cfg(feature = "hyper")]
+async fn hyper_webhook_server() {
+  use tokio::sync::mpsc;
+
+  let (tx, mut rx) = mpsc::unbounded_channel();
+  let server = crate::hyper::listen("127.0.0.1:0", "password", move |vote: crate::Vote| {
+    let tx = tx.clone();
+
+    async move {
+      tx.send(vote.voter_id).unwrap();
+    }
+  })
+  .await
+  .unwrap();
+
+  let url = format!("http://{}/webhook", server.local_addr());
+  let http = reqwest::Client::new();
+  let send = |authorization: &'static str, body: &'static str| {
+    http
+      .post(&url)
+      .header("Authorization", authorization)
+      .body(body)
+      .send()
+  };
+
+  let body = r#"{"bot":"1","user":"2","type":"upvote"}"#;
+
+  assert_eq!(send("password", body).await.unwrap().status(), 200);
+  assert_eq!(rx.recv().await, Some(2));
+  assert_eq!(send("wrong", body).await.unwrap().status(), 401);
+  assert_eq!(send("password", "{}").await.unwrap().status(), 400);
+
+  let oversized = http
+    .post(&url)
+    .header("Authorization", "password")
+    .body(" ".repeat(8192))
+    .send()
+    .await
+    .unwrap();
+
+  assert_eq!(oversized.status(), 413);
+
+  server.shutdown().await;
+}
+
+#[cfg(feature = "autoposter")]
+struct MockHandler(crate::SharedStats);
+
+#[cfg(feature = "autoposter")]
+impl crate::autoposter::Handler for MockHandler {
+  fn stats(&self) -> &crate::SharedStats {
+    &self.0
+  }
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_post_immediately() {
+  use crate::{Autoposter, SharedStats};
+
+  let server = MockServer::new(|method, path| match (method, path) {
+    ("POST", "/api/bots/stats") => (200, "{}"),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let stats = Arc::new(SharedStats::new());
+  let mut autoposter = Autoposter::builder(
+    server.client(),
+    Arc::clone(&stats),
+    Duration::from_secs(900),
+  )
+  .post_immediately(true)
+  .build();
+
+  // the placeholder stats are never posted.
+  assert!(
+    tokio::time::timeout(Duration::from_millis(100), autoposter.recv())
+      .await
+      .is_err()
+  );
+  assert!(autoposter.last_post_at().is_none());
+
+  stats.write().await.set_server_count(2);
+
+  assert_eq!(
+    autoposter.recv().await.unwrap().unwrap().server_count(),
+    Some(2)
+  );
+  assert_eq!(autoposter.success_count(), 1);
+  assert_eq!(autoposter.failure_count(), 0);
+
+  autoposter.shutdown().await;
+
+  // already fed stats are posted right away, even if they didn't change since their last post.
+  let mut autoposter = Autoposter::builder(server.client(), stats, Duration::from_secs(900))
+    .post_immediately(true)
+    .build();
+
+  assert_eq!(
+    autoposter.recv().await.unwrap().unwrap().server_count(),
+    Some(2)
+  );
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_on_error() {
+  use crate::{Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (500, "{}")).await;
+  let errors = Arc::new(AtomicUsize::new(0));
+  let counter = Arc::clone(&errors);
+
+  let stats = SharedStats::new();
+
+  stats.write().await.set_server_count(1);
+
+  let mut autoposter = Autoposter::builder(
+    server.client(),
+    MockHandler(stats),
+    Duration::from_secs(900),
+  )
+  .post_immediately(true)
+  .on_error(move |err| {
+    assert!(matches!(
+      err,
+      Error::InternalServerError { status: 500, .. }
+    ));
+    counter.fetch_add(1, Ordering::SeqCst);
+  })
+  .build();
+
+  assert!(autoposter.recv().await.unwrap().is_err());
+  assert_eq!(errors.load(Ordering::SeqCst), 1);
+  assert_eq!(autoposter.success_count(), 0);
+  assert_eq!(autoposter.failure_count(), 1);
+  assert!(autoposter.last_post_at().is_some());
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_shutdown() {
+  use crate::{Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  let stats = SharedStats::new();
+
+  stats.write().await.set_server_count(1);
+
+  let mut autoposter = Autoposter::builder(
+    server.client(),
+    MockHandler(stats),
+    Duration::from_secs(900),
+  )
+  .post_immediately(true)
+  .build();
+
+  assert!(autoposter.recv().await.unwrap().is_ok());
+
+  tokio::time::timeout(Duration::from_secs(5), autoposter.shutdown())
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn autoposter_try_new() {
+  use crate::{
+    autoposter::{IntervalTooShort, MIN_INTERVAL},
+    Autoposter, SharedStats,
+  };
+
+  let client = Client::new(MOCK_TOKEN.to_string());
+
+  assert!(matches!(
+    Autoposter::try_new(
+      &client,
+      MockHandler(SharedStats::new()),
+      MIN_INTERVAL - Duration::from_secs(1)
+    ),
+    Err(IntervalTooShort)
+  ));
+  assert!(Autoposter::try_new(&client, MockHandler(SharedStats::new()), MIN_INTERVAL).is_ok());
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_with_fn() {
+  use crate::{autoposter::Handler, Autoposter};
+
+  let server = MockServer::new(|method, path| match (method, path) {
+    ("POST", "/api/bots/stats") => (200, "{}"),
+    _ => (404, "{}"),
+  })
+  .await;
+
+  let mut autoposter = Autoposter::with_fn(server.client(), Duration::from_secs(900), || async {
+    42usize
+  });
+
+  assert!(autoposter.recv().await.unwrap().is_ok());
+  assert_eq!(autoposter.stats().write().await.server_count(), Some(42));
+}
+
+#[tokio::test]
+#[cfg(all(feature = "autoposter", feature = "twilight"))]
+async fn autoposter_twilight_handler() {
+  use crate::{autoposter::Handler, Autoposter};
+  use twilight_model::{
+    gateway::{
+      event::Event,
+      payload::incoming::{GuildDelete, Ready},
+    },
+    id::Id,
+  };
+
+  let ready: Ready = serde_json::from_str(
+    r#"{
+      "application": {"id": "1", "flags": 0},
+      "guilds": [{"id": "1", "unavailable": true}, {"id": "2", "unavailable": true}],
+      "resume_gateway_url": "wss://gateway.discord.gg",
+      "session_id": "session",
+      "user": {"id": "1", "username": "bot", "discriminator": "0001", "avatar": null, "bot": true, "mfa_enabled": false},
+      "v": 10
+    }"#,
+  )
+  .unwrap();
+
+  let autoposter = Autoposter::twilight(
+    Client::new(MOCK_TOKEN.to_string()),
+    Duration::from_secs(900),
+  );
+
+  autoposter.handle(&Event::Ready(Box::new(ready))).await;
+
+  assert_eq!(autoposter.stats().write().await.server_count(), Some(2));
+
+  autoposter
+    .handle(&Event::GuildDelete(GuildDelete {
+      id: Id::new(1),
+      unavailable: false,
+    }))
+    .await;
+
+  assert_eq!(autoposter.stats().write().await.server_count(), Some(1));
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_skips_unchanged_stats() {
+  use crate::{autoposter::Handler, Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  let mut autoposter = Autoposter::new(
+    server.client(),
+    MockHandler(SharedStats::new()),
+    Duration::from_secs(900),
+  );
+
+  autoposter.stats().write().await.set_server_count(1);
+
+  let posted = autoposter.recv().await.unwrap().unwrap();
+
+  assert_eq!(posted.server_count(), Some(1));
+
+  let changed = || tokio::time::timeout(Duration::from_millis(50), autoposter.stats().wait());
+
+  autoposter.stats().write().await.set_server_count(1);
+
+  assert!(changed().await.is_err());
+
+  autoposter.stats().always_post(true);
+  autoposter.stats().write().await.set_server_count(1);
+
+  assert!(changed().await.is_ok());
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_unchecked_interval() {
+  use crate::{autoposter::Handler, Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  // the autoposter can fully own its client.
+  let mut autoposter = Autoposter::new_unchecked(
+    server.client(),
+    MockHandler(SharedStats::new()),
+    Duration::from_millis(10),
+  );
+
+  for server_count in 1..=2 {
+    autoposter
+      .stats()
+      .write()
+      .await
+      .set_server_count(server_count);
+
+    let result = tokio::time::timeout(Duration::from_secs(5), autoposter.recv()).await;
+
+    assert!(result.unwrap().unwrap().is_ok());
+  }
+
+  assert_eq!(autoposter.success_count(), 2);
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_shared_stats() {
+  use crate::{Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  let stats = Arc::new(SharedStats::new());
+  let mut autoposter = Autoposter::new_unchecked(
+    server.client(),
+    Arc::clone(&stats),
+    Duration::from_millis(10),
+  );
+
+  stats.write().await.set_server_count(3);
+
+  let result = tokio::time::timeout(Duration::from_secs(5), autoposter.recv()).await;
+
+  assert_eq!(result.unwrap().unwrap().unwrap().server_count(), Some(3));
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_stopped() {
+  use crate::{Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (500, "{}")).await;
+  let stats = SharedStats::new();
+
+  stats.write().await.set_server_count(1);
+
+  let mut autoposter = Autoposter::builder_unchecked(
+    server.client(),
+    MockHandler(stats),
+    Duration::from_millis(10),
+  )
+  .post_immediately(true)
+  .on_error(|_| panic!("the autoposter thread panicked on purpose"))
+  .build();
+
+  let result = tokio::time::timeout(Duration::from_secs(5), autoposter.recv()).await;
+
+  assert!(matches!(
+    result.unwrap(),
+    Some(Err(Error::AutoposterStopped))
+  ));
+  assert!(autoposter.recv().await.is_none());
+
+  tokio::time::timeout(Duration::from_secs(5), async {
+    while autoposter.is_running() {
+      sleep(Duration::from_millis(5)).await;
+    }
+  })
+  .await
+  .unwrap();
+}
+
+#[tokio::test]
+#[cfg(feature = "autoposter")]
+async fn mock_autoposter_recv_changed() {
+  use crate::{autoposter::Handler, Autoposter, SharedStats};
+
+  let server = MockServer::new(|_, _| (200, "{}")).await;
+  let mut autoposter = Autoposter::new_unchecked(
+    server.client(),
+    MockHandler(SharedStats::new()),
+    Duration::from_millis(10),
+  );
+
+  autoposter.stats().always_post(true);
+
+  for (server_count, posts) in [(1, 1), (1, 2), (2, 3)] {
+    autoposter
+      .stats()
+      .write()
+      .await
+      .set_server_count(server_count);
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+      while autoposter.success_count() < posts {
+        sleep(Duration::from_millis(5)).await;
+      }
+    })
+    .await
+    .unwrap();
+  }
+
+  for server_count in [1, 2] {
+    let stats = autoposter.recv_changed().await.unwrap().unwrap();
+
+    assert_eq!(stats.server_count(), Some(server_count));
+  }
+}
+
+#[tokio::test]
+#[cfg(feature = "cache")]
+async fn mock_bot_cache() {
+  static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+  let server = MockServer::new(|_, _| {
+    REQUESTS.fetch_add(1, Ordering::SeqCst);
+
+    (200, mock_bot!("1"))
+  })
+  .await;
+
+  let client = Client::builder(MOCK_TOKEN.to_string())
+    .base_url(format!("http://{}/api", server.addr))
+    .cache_ttl(Duration::from_secs(60))
+    .build();
+
+  assert_eq!(client.get_bot(1).await.unwrap().id, 1);
+  assert_eq!(client.get_bot(1).await.unwrap().id, 1);
+
+  assert_eq!(REQUESTS.load(Ordering::SeqCst), 1);
+
+  client.clear_cache();
+  assert_eq!(client.get_bot(1).await.unwrap().id, 1);
+
+  assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+}
@@ -1,22 +1,24 @@
 use crate::{snowflake, Error};
 use base64::{prelude::BASE64_STANDARD, Engine};
-use chrono::{DateTime, TimeZone, Utc};
-use reqwest::Response;
-use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+use futures_util::{stream, Stream};
+use reqwest::{Response, StatusCode};
+use serde::{
+  de::{DeserializeOwned, IgnoredAny},
+  Deserialize, Deserializer,
+};
+use std::sync::Arc;
 
 // TODO: remove these utility deprecation helpers soon
 
 #[inline(always)]
-pub(crate) fn deserialize_immediate_default<'de, D, T>(_deserializer: D) -> Result<T, D::Error>
+pub(crate) fn deserialize_immediate_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
   D: Deserializer<'de>,
   T: Default,
 {
-  Ok(T::default())
+  IgnoredAny::deserialize(deserializer).map(|_| T::default())
 }
 
-const DISCORD_EPOCH: u64 = 1_420_070_400_000;
-
 macro_rules! debug_struct {
   (
     $(#[$struct_attr:meta])*
@@ -63,13 +65,15 @@ macro_rules! debug_struct {
       )*)?
     }
 
-    $(impl $struct_name {
+    $(#[allow(deprecated)]
+    impl $struct_name {
       $(
         $(#[$getter_attr])*
         pub fn $getter_name(&$self) -> $getter_type $getter_code
       )*
     })?
 
+    #[allow(deprecated)]
     impl std::fmt::Debug for $struct_name {
       fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         fmt
@@ -116,12 +120,43 @@ where
   Option::deserialize(deserializer).map(|res| res.unwrap_or_default())
 }
 
-#[inline(always)]
-pub(crate) fn get_creation_date(id: u64) -> DateTime<Utc> {
-  Utc
-    .timestamp_millis_opt(((id >> 22) + DISCORD_EPOCH) as _)
-    .single()
-    .unwrap()
+// falls back to None instead of failing the whole deserialization if the value is missing, null or malformed.
+pub(crate) fn deserialize_lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: DeserializeOwned,
+{
+  serde_json::Value::deserialize(deserializer).map(|value| serde_json::from_value(value).ok())
+}
+
+// any response body that can't be deserialized in a successful response is a deserialization error, anything else is the server's fault.
+fn from_json_slice<T>(status: StatusCode, bytes: &[u8]) -> crate::Result<T>
+where
+  T: DeserializeOwned,
+{
+  let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+
+  serde_path_to_error::deserialize(&mut deserializer)
+    .map_err(|err| (err.path().to_string(), err.into_inner()))
+    .and_then(|value| {
+      deserializer
+        .end()
+        .map(|_| value)
+        .map_err(|err| (String::from("."), err))
+    })
+    .map_err(|(path, err)| {
+      if status.is_success() {
+        Error::Deserialize {
+          path,
+          source: Arc::new(err),
+        }
+      } else {
+        Error::InternalServerError {
+          status: status.as_u16(),
+          body: truncate_body(&String::from_utf8_lossy(bytes)),
+        }
+      }
+    })
 }
 
 #[inline(always)]
@@ -129,21 +164,196 @@ pub(crate) async fn parse_json<T>(response: Response) -> crate::Result<T>
 where
   T: DeserializeOwned,
 {
-  if let Ok(bytes) = response.bytes().await {
-    if let Ok(json) = serde_json::from_slice(&bytes) {
-      return Ok(json);
+  let status = response.status();
+  let bytes = response
+    .bytes()
+    .await
+    .map_err(|err| Error::InternalClientError(Arc::new(err)))?;
+
+  from_json_slice(status, &bytes)
+}
+
+// splits a JSON array into its elements as its bytes arrive, so each element can be deserialized without buffering the whole array.
+#[derive(Default)]
+struct JsonArraySplitter {
+  buffer: Vec<u8>,
+  scanned: usize,
+  started: bool,
+  finished: bool,
+  element_start: Option<usize>,
+  depth: usize,
+  in_string: bool,
+  escaped: bool,
+}
+
+impl JsonArraySplitter {
+  #[inline(always)]
+  fn push(&mut self, chunk: &[u8]) {
+    self.buffer.extend_from_slice(chunk);
+  }
+
+  // returns the next complete element, Ok(None) if more bytes are needed or the array has ended.
+  fn next_element(&mut self) -> Result<Option<Vec<u8>>, ()> {
+    while self.scanned < self.buffer.len() && !self.finished {
+      let index = self.scanned;
+      let byte = self.buffer[index];
+
+      self.scanned += 1;
+
+      let Some(start) = self.element_start else {
+        match byte {
+          b' ' | b'\t' | b'\n' | b'\r' => {}
+          b'[' if !self.started => self.started = true,
+          b',' if self.started => {}
+          b']' if self.started => self.finished = true,
+          _ if self.started => {
+            self.element_start = Some(index);
+            self.scanned = index;
+          }
+          _ => return Err(()),
+        }
+
+        continue;
+      };
+
+      let end = if self.in_string {
+        match (self.escaped, byte) {
+          (true, _) => self.escaped = false,
+          (false, b'\\') => self.escaped = true,
+          (false, b'"') => self.in_string = false,
+          _ => {}
+        }
+
+        (!self.in_string && self.depth == 0).then_some(index + 1)
+      } else {
+        match byte {
+          b'"' => {
+            self.in_string = true;
+            None
+          }
+          b'{' | b'[' => {
+            self.depth += 1;
+            None
+          }
+          b'}' | b']' if self.depth > 0 => {
+            self.depth -= 1;
+            (self.depth == 0).then_some(index + 1)
+          }
+          // the end of a scalar element, which is scanned again as a separator.
+          b',' | b']' | b' ' | b'\t' | b'\n' | b'\r' if self.depth == 0 => {
+            self.scanned = index;
+            Some(index)
+          }
+          _ => None,
+        }
+      };
+
+      if let Some(end) = end {
+        let element = self.buffer[start..end].to_vec();
+
+        self.buffer.drain(..end);
+        self.scanned -= end;
+        self.element_start = None;
+
+        return Ok(Some(element));
+      }
     }
+
+    Ok(None)
   }
+}
+
+// streams the elements of a JSON array response, deserializing each of them as soon as it has fully arrived.
+pub(crate) fn parse_json_array_stream<T>(response: Response) -> impl Stream<Item = crate::Result<T>>
+where
+  T: DeserializeOwned,
+{
+  let status = response.status();
 
-  Err(Error::InternalServerError)
+  stream::try_unfold(
+    (response, JsonArraySplitter::default()),
+    move |(mut response, mut splitter)| async move {
+      // deserializes what's left of the array in order to report why it's malformed.
+      let malformed = |splitter: &JsonArraySplitter| {
+        from_json_slice::<Vec<IgnoredAny>>(status, &splitter.buffer)
+          .err()
+          .unwrap_or_else(|| Error::InternalServerError {
+            status: status.as_u16(),
+            body: truncate_body(&String::from_utf8_lossy(&splitter.buffer)),
+          })
+      };
+
+      loop {
+        match splitter.next_element() {
+          Ok(Some(element)) => {
+            return from_json_slice(status, &element)
+              .map(|item| Some((item, (response, splitter))));
+          }
+          Ok(None) if splitter.finished => return Ok(None),
+          Ok(None) => {}
+          Err(()) => return Err(malformed(&splitter)),
+        }
+
+        match response.chunk().await {
+          Ok(Some(chunk)) => splitter.push(&chunk),
+          Ok(None) => return Err(malformed(&splitter)),
+          Err(err) => return Err(Error::InternalClientError(Arc::new(err))),
+        }
+      }
+    },
+  )
 }
 
-pub(crate) fn get_avatar(hash: &Option<String>, id: u64) -> String {
+const MAX_ERROR_BODY_LEN: usize = 512;
+
+pub(crate) fn truncate_body(body: &str) -> String {
+  let mut end = body.len().min(MAX_ERROR_BODY_LEN);
+
+  while !body.is_char_boundary(end) {
+    end -= 1;
+  }
+
+  body[..end].to_owned()
+}
+
+const MIN_AVATAR_SIZE: u16 = 16;
+const MAX_AVATAR_SIZE: u16 = 4096;
+
+// rounds the size to the nearest power of two supported by Discord's CDN.
+fn avatar_size(size: u16) -> u16 {
+  let size = size.clamp(MIN_AVATAR_SIZE, MAX_AVATAR_SIZE);
+  let lower = 1 << (u16::BITS - 1 - size.leading_zeros());
+
+  if size - lower >= lower / 2 {
+    lower << 1
+  } else {
+    lower
+  }
+}
+
+#[inline(always)]
+pub(crate) fn is_animated_avatar(hash: &str) -> bool {
+  hash.starts_with("a_")
+}
+
+#[inline(always)]
+pub(crate) fn get_avatar(hash: Option<&str>, id: u64) -> String {
+  get_avatar_with_size(hash, id, 1024)
+}
+
+pub(crate) fn get_avatar_with_size(hash: Option<&str>, id: u64, size: u16) -> String {
   match hash {
     Some(hash) => {
-      let ext = if hash.starts_with("a_") { "gif" } else { "png" };
+      let ext = if is_animated_avatar(hash) {
+        "gif"
+      } else {
+        "png"
+      };
 
-      format!("https://cdn.discordapp.com/avatars/{id}/{hash}.{ext}?size=1024")
+      format!(
+        "https://cdn.discordapp.com/avatars/{id}/{hash}.{ext}?size={}",
+        avatar_size(size)
+      )
     }
     _ => format!(
       "https://cdn.discordapp.com/embed/avatars/{}.png",
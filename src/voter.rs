@@ -1,20 +1,27 @@
 use crate::{snowflake, util};
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::{
+  borrow::Cow,
+  hash::{Hash, Hasher},
+};
 
 #[derive(Deserialize)]
 pub(crate) struct Voted {
-  pub(crate) voted: u8,
+  pub(crate) voted: u32,
 }
 
 util::debug_struct! {
   /// A struct representing a user who has voted on a bot listed on [Top.gg](https://top.gg). (See [`Client::get_voters`][crate::Client::get_voters])
   #[must_use]
-  #[derive(Clone, Deserialize)]
+  #[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
   Voter {
     public {
       /// The Discord ID of this user.
-      #[serde(deserialize_with = "snowflake::deserialize")]
+      #[serde(
+        serialize_with = "snowflake::serialize",
+        deserialize_with = "snowflake::deserialize"
+      )]
       id: u64,
 
       /// The username of this user.
@@ -30,7 +37,7 @@ util::debug_struct! {
       #[must_use]
       #[inline(always)]
       created_at: DateTime<Utc> => {
-        util::get_creation_date(self.id)
+        snowflake::snowflake_timestamp(self.id)
       }
 
       /// Retrieves the Discord avatar URL of this user.
@@ -39,8 +46,119 @@ util::debug_struct! {
       #[must_use]
       #[inline(always)]
       avatar: String => {
-        util::get_avatar(&self.avatar, self.id)
+        util::get_avatar(self.avatar.as_deref(), self.id)
       }
     }
   }
 }
+
+// hashed by ID only, which is consistent with equality as equal voters always share the same ID.
+impl Hash for Voter {
+  #[inline(always)]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.id.hash(state);
+  }
+}
+
+impl Voter {
+  /// Retrieves the raw Discord avatar hash of this user, or `None` if it uses a default avatar.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar_hash(&self) -> Option<&str> {
+    self.avatar.as_deref()
+  }
+
+  /// Checks if this user's avatar is animated, meaning its [avatar URL][Voter::avatar] is a GIF instead of a PNG.
+  #[must_use]
+  #[inline(always)]
+  pub fn is_animated_avatar(&self) -> bool {
+    self.avatar.as_deref().is_some_and(util::is_animated_avatar)
+  }
+
+  /// Retrieves the avatar URL of this user with a specific size.
+  ///
+  /// Discord only supports sizes that are powers of two from 16 to 4096, so `size` is rounded to the nearest supported size. Its format will either be PNG or GIF if animated.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar_with_size(&self, size: u16) -> String {
+    util::get_avatar_with_size(self.avatar.as_deref(), self.id, size)
+  }
+}
+
+/// A borrowed form of [`Voter`], deserialized from a response body without allocating for each of its fields unless they need unescaping.
+///
+/// This is useful for processing lots of voters at once. (See [`Client::get_voters_page_raw`][crate::Client::get_voters_page_raw])
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::voter::VoterRef;
+///
+/// # async fn example(client: topgg::Client) -> topgg::Result<()> {
+/// let body = client.get_voters_page_raw(1).await?;
+/// let voters: Vec<VoterRef<'_>> = serde_json::from_slice(&body).unwrap();
+///
+/// for voter in &voters {
+///   println!("{}", voter.username);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct VoterRef<'a> {
+  /// The Discord ID of this user.
+  #[serde(deserialize_with = "snowflake::deserialize")]
+  pub id: u64,
+
+  /// The username of this user.
+  #[serde(borrow)]
+  pub username: Cow<'a, str>,
+
+  #[serde(borrow, default)]
+  avatar: Option<Cow<'a, str>>,
+}
+
+impl VoterRef<'_> {
+  /// Retrieves the creation date of this user.
+  #[must_use]
+  #[inline(always)]
+  pub fn created_at(&self) -> DateTime<Utc> {
+    snowflake::snowflake_timestamp(self.id)
+  }
+
+  /// Retrieves the raw Discord avatar hash of this user, or `None` if it uses a default avatar.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar_hash(&self) -> Option<&str> {
+    self.avatar.as_deref()
+  }
+
+  /// Retrieves the Discord avatar URL of this user.
+  ///
+  /// Its format will either be PNG or GIF if animated.
+  #[must_use]
+  #[inline(always)]
+  pub fn avatar(&self) -> String {
+    util::get_avatar(self.avatar.as_deref(), self.id)
+  }
+
+  /// Converts this into an owned [`Voter`].
+  #[inline(always)]
+  pub fn into_owned(self) -> Voter {
+    Voter {
+      id: self.id,
+      username: self.username.into_owned(),
+      avatar: self.avatar.map(Cow::into_owned),
+    }
+  }
+}
+
+impl From<VoterRef<'_>> for Voter {
+  #[inline(always)]
+  fn from(voter: VoterRef<'_>) -> Self {
+    voter.into_owned()
+  }
+}
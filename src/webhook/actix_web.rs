@@ -1,12 +1,13 @@
-use crate::{IncomingVote, Vote};
+use crate::{IncomingVote, Vote, WebhookSecret};
 use actix_web::{
   dev::Payload,
-  error::{Error, ErrorUnauthorized},
-  web::Json,
+  error::{Error, ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+  web::{Data, Json},
   FromRequest, HttpRequest,
 };
 use core::{
   future::Future,
+  ops::Deref,
   pin::Pin,
   task::{ready, Context, Poll},
 };
@@ -51,3 +52,95 @@ impl FromRequest for IncomingVote {
     }
   }
 }
+
+/// An [`actix-web`](https://actix.rs) extractor for an authenticated incoming [`Vote`].
+///
+/// The webhook password is retrieved from your application's data through a [`Data<WebhookSecret>`][Data]. Requests with a missing or mismatched `Authorization` header are rejected with a 401 (Unauthorized) before their body is read, and requests with a malformed body are rejected with a 400 (Bad Request), while a missing [`WebhookSecret`] results in a 500 (Internal Server Error).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use actix_web::{post, web::Data, App, HttpServer};
+/// use std::io;
+/// use topgg::{actix_web::TopggVote, WebhookSecret};
+///
+/// #[post("/webhook")]
+/// async fn webhook(vote: TopggVote) -> &'static str {
+///   println!("{:?}", vote.0);
+///
+///   "OK"
+/// }
+///
+/// #[actix_web::main]
+/// async fn main() -> io::Result<()> {
+///   let secret = Data::new(WebhookSecret::new(env!("TOPGG_WEBHOOK_PASSWORD")));
+///
+///   HttpServer::new(move || App::new().app_data(secret.clone()).service(webhook))
+///     .bind("127.0.0.1:8080")?
+///     .run()
+///     .await
+/// }
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
+pub struct TopggVote(pub Vote);
+
+impl Deref for TopggVote {
+  type Target = Vote;
+
+  #[inline(always)]
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+#[doc(hidden)]
+pub struct TopggVoteFut {
+  json_fut: Result<<Json<Vote> as FromRequest>::Future, Option<Error>>,
+}
+
+impl Future for TopggVoteFut {
+  type Output = Result<TopggVote, Error>;
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    match &mut self.json_fut {
+      Ok(json_fut) => Poll::Ready(
+        ready!(Pin::new(json_fut).poll(cx))
+          .map(|json| TopggVote(json.into_inner()))
+          .map_err(ErrorBadRequest),
+      ),
+      Err(err) => Poll::Ready(Err(err.take().expect("polled after completion"))),
+    }
+  }
+}
+
+impl FromRequest for TopggVote {
+  type Error = Error;
+  type Future = TopggVoteFut;
+
+  fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+    let json_fut = match req.app_data::<Data<WebhookSecret>>() {
+      Some(secret) => {
+        let authorized = req
+          .headers()
+          .get("Authorization")
+          .and_then(|authorization| authorization.to_str().ok())
+          .is_some_and(|authorization| secret.verify(authorization));
+
+        if authorized {
+          Ok(Json::from_request(req, payload))
+        } else {
+          Err(Some(ErrorUnauthorized("401")))
+        }
+      }
+      _ => Err(Some(ErrorInternalServerError(
+        "missing WebhookSecret app data",
+      ))),
+    };
+
+    TopggVoteFut { json_fut }
+  }
+}
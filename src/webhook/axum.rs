@@ -1,49 +1,104 @@
-use crate::VoteHandler;
+use crate::{Vote, VoteDispatcher, VoteHandler, WebhookSecret};
 use axum::{
-  extract::State,
+  async_trait,
+  body::Bytes,
+  extract::{FromRef, FromRequest, Request, State},
   http::{HeaderMap, StatusCode},
   response::{IntoResponse, Response},
   routing::post,
   Router,
 };
+use core::ops::Deref;
 use std::sync::Arc;
 
-struct WebhookState<T> {
-  state: Arc<T>,
-  password: Arc<String>,
-}
+/// An [`axum`] extractor for an authenticated incoming [`Vote`].
+///
+/// The webhook password is retrieved from your application's state through a [`WebhookSecret`] (See [`FromRef`]), so it can be [rotated][WebhookSecret::rotate] at runtime through any clone of it. Requests with a missing or mismatched `Authorization` header are rejected with a 401 (Unauthorized), while requests with a malformed body are rejected with a 400 (Bad Request).
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use axum::{routing::post, Router};
+/// use topgg::{axum::TopggVote, WebhookSecret};
+///
+/// async fn webhook(TopggVote(vote): TopggVote) -> &'static str {
+///   println!("{:?}", vote);
+///
+///   "OK"
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///   let app = Router::new()
+///     .route("/webhook", post(webhook))
+///     .with_state(WebhookSecret::new(env!("TOPGG_WEBHOOK_PASSWORD")));
+///
+///   let listener = tokio::net::TcpListener::bind("127.0.0.1:8080").await.unwrap();
+///
+///   axum::serve(listener, app).await.unwrap();
+/// }
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "axum")))]
+pub struct TopggVote(pub Vote);
+
+impl Deref for TopggVote {
+  type Target = Vote;
 
-impl<T> Clone for WebhookState<T> {
   #[inline(always)]
-  fn clone(&self) -> Self {
-    Self {
-      state: Arc::clone(&self.state),
-      password: Arc::clone(&self.password),
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+#[async_trait]
+impl<S> FromRequest<S> for TopggVote
+where
+  WebhookSecret: FromRef<S>,
+  S: Send + Sync,
+{
+  type Rejection = StatusCode;
+
+  async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+    let secret = WebhookSecret::from_ref(state);
+    let authorized = req
+      .headers()
+      .get("Authorization")
+      .and_then(|authorization| authorization.to_str().ok())
+      .is_some_and(|authorization| secret.verify(authorization));
+
+    if !authorized {
+      return Err(StatusCode::UNAUTHORIZED);
     }
+
+    let body = String::from_request(req, state)
+      .await
+      .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+    serde_json::from_str(&body)
+      .map(Self)
+      .map_err(|_| StatusCode::BAD_REQUEST)
   }
 }
 
 async fn handler<T>(
   headers: HeaderMap,
-  State(webhook): State<WebhookState<T>>,
-  body: String,
+  State(dispatcher): State<VoteDispatcher<T>>,
+  body: Bytes,
 ) -> Response
 where
   T: VoteHandler,
 {
-  if let Some(authorization) = headers.get("Authorization") {
-    if let Ok(authorization) = authorization.to_str() {
-      if authorization == *(webhook.password) {
-        if let Ok(vote) = serde_json::from_str(&body) {
-          webhook.state.voted(vote).await;
+  let authorization = headers
+    .get("Authorization")
+    .and_then(|authorization| authorization.to_str().ok());
 
-          return (StatusCode::OK, ()).into_response();
-        }
-      }
-    }
-  }
+  let status = dispatcher.dispatch(authorization, &body).await;
 
-  (StatusCode::UNAUTHORIZED, ()).into_response()
+  (StatusCode::from_u16(status).unwrap(), ()).into_response()
 }
 
 /// Creates a new [`axum`] [`Router`] for adding an on-vote event handler to your application logic.
@@ -95,8 +150,8 @@ where
 {
   Router::new()
     .route("/", post(handler::<T>))
-    .with_state(WebhookState {
-      state,
-      password: Arc::new(password),
+    .with_state(VoteDispatcher {
+      secret: WebhookSecret::new(password),
+      handler: state,
     })
 }
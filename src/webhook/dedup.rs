@@ -0,0 +1,96 @@
+use crate::Vote;
+use core::time::Duration;
+use std::{
+  collections::{HashSet, VecDeque},
+  sync::Mutex,
+  time::Instant,
+};
+
+// receiver ID and voter ID.
+type Key = (u64, u64);
+
+struct Seen {
+  keys: HashSet<Key>,
+  order: VecDeque<(Key, Instant)>,
+}
+
+/// A bounded record of recently received [`Vote`]s, used to detect [Top.gg](https://top.gg) retrying the same webhook delivery.
+///
+/// Since a user can only vote for the same bot/server once in a while, a vote from the same user for the same bot/server within `window` is considered a duplicate. At most `capacity` votes are remembered at once, forgetting the oldest ones first.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use core::time::Duration;
+/// use topgg::{Vote, VoteDeduplicator};
+///
+/// let deduplicator = VoteDeduplicator::new(Duration::from_secs(3600), 10_000);
+///
+/// # fn example(deduplicator: &VoteDeduplicator, vote: Vote) {
+/// if !deduplicator.is_duplicate(&vote) {
+///   // grant rewards...
+/// }
+/// # }
+/// ```
+#[must_use]
+pub struct VoteDeduplicator {
+  window: Duration,
+  capacity: usize,
+  seen: Mutex<Seen>,
+}
+
+impl VoteDeduplicator {
+  /// Creates a new deduplicator that remembers at most `capacity` votes, each for `window` long.
+  pub fn new(window: Duration, capacity: usize) -> Self {
+    Self {
+      window,
+      capacity,
+      seen: Mutex::new(Seen {
+        keys: HashSet::new(),
+        order: VecDeque::new(),
+      }),
+    }
+  }
+
+  /// Checks if this vote has already been received within the window, remembering it if it hasn't.
+  #[must_use]
+  pub fn is_duplicate(&self, vote: &Vote) -> bool {
+    let key = (vote.receiver_id, vote.voter_id);
+    let now = Instant::now();
+    let mut seen = self.seen.lock().unwrap();
+
+    while let Some(&(oldest, received_at)) = seen.order.front() {
+      if now.duration_since(received_at) < self.window {
+        break;
+      }
+
+      seen.order.pop_front();
+      seen.keys.remove(&oldest);
+    }
+
+    if seen.keys.contains(&key) {
+      return true;
+    }
+
+    seen.keys.insert(key);
+    seen.order.push_back((key, now));
+
+    while seen.order.len() > self.capacity {
+      if let Some((oldest, _)) = seen.order.pop_front() {
+        seen.keys.remove(&oldest);
+      }
+    }
+
+    false
+  }
+
+  /// Forgets every vote remembered by this deduplicator.
+  pub fn clear(&self) {
+    let mut seen = self.seen.lock().unwrap();
+
+    seen.keys.clear();
+    seen.order.clear();
+  }
+}
@@ -0,0 +1,182 @@
+use crate::{VoteDispatcher, VoteHandler, WebhookSecret};
+use core::{
+  convert::Infallible,
+  future::Future,
+  pin::Pin,
+  task::{Context, Poll},
+  time::Duration,
+};
+use http_body_util::{BodyExt, Full, LengthLimitError, Limited};
+use hyper::{
+  body::{Bytes, Incoming},
+  server::conn::http1,
+  service::service_fn,
+  Method, Request, Response, StatusCode,
+};
+use hyper_util::rt::TokioIo;
+use std::{io, net::SocketAddr, panic, sync::Arc};
+use tokio::{
+  net::{TcpListener, ToSocketAddrs},
+  sync::Notify,
+  task::JoinHandle,
+  time::sleep,
+};
+
+// votes are tiny, anything bigger than this isn't coming from Top.gg.
+const MAX_BODY_SIZE: usize = 4096;
+
+// prevents spinning when accepting connections keeps failing, like when running out of file descriptors.
+const ACCEPT_ERROR_DELAY: Duration = Duration::from_millis(100);
+
+/// A handle to a running webhook server. (See [`listen`])
+///
+/// `await`ing this handle waits until the server stops, while dropping it leaves the server running in the background.
+#[must_use]
+#[cfg_attr(docsrs, doc(cfg(feature = "hyper")))]
+pub struct WebhookServer {
+  local_addr: SocketAddr,
+  shutdown: Arc<Notify>,
+  task: JoinHandle<()>,
+}
+
+impl WebhookServer {
+  /// Retrieves the local address this webhook server is listening on.
+  #[must_use]
+  #[inline(always)]
+  pub const fn local_addr(&self) -> SocketAddr {
+    self.local_addr
+  }
+
+  /// Stops accepting new connections and waits until the server stops. Connections that are already accepted are left to finish on their own.
+  pub async fn shutdown(self) {
+    self.shutdown.notify_one();
+    self.await
+  }
+}
+
+impl Future for WebhookServer {
+  type Output = ();
+
+  fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    Pin::new(&mut self.task).poll(cx).map(|res| {
+      if let Err(err) = res {
+        if err.is_panic() {
+          panic::resume_unwind(err.into_panic());
+        }
+      }
+    })
+  }
+}
+
+#[inline(always)]
+fn respond(status: StatusCode) -> Response<Full<Bytes>> {
+  let mut response = Response::new(Full::default());
+  *response.status_mut() = status;
+
+  response
+}
+
+async fn handle<H>(req: Request<Incoming>, dispatcher: &VoteDispatcher<H>) -> Response<Full<Bytes>>
+where
+  H: VoteHandler,
+{
+  if req.method() != Method::POST {
+    return respond(StatusCode::METHOD_NOT_ALLOWED);
+  }
+
+  let authorization = req
+    .headers()
+    .get("Authorization")
+    .and_then(|authorization| authorization.to_str().ok())
+    .map(ToOwned::to_owned);
+
+  let body = match Limited::new(req.into_body(), MAX_BODY_SIZE).collect().await {
+    Ok(body) => body.to_bytes(),
+    Err(err) if err.is::<LengthLimitError>() => return respond(StatusCode::PAYLOAD_TOO_LARGE),
+    Err(_) => return respond(StatusCode::BAD_REQUEST),
+  };
+
+  let status = dispatcher.dispatch(authorization.as_deref(), &body).await;
+
+  respond(StatusCode::from_u16(status).unwrap())
+}
+
+/// Spins up a minimal webhook server on a specific address, calling a [`VoteHandler`] for every authenticated incoming [`Vote`][crate::Vote].
+///
+/// Every `POST` request on any path is handled. Requests with a missing or mismatched `Authorization` header are rejected with a 401 (Unauthorized), requests with a body larger than 4 KiB are rejected with a 413 (Payload Too Large), while requests with a malformed body are rejected with a 400 (Bad Request).
+///
+/// # Errors
+///
+/// Errors if the server can't bind to the specified address.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// #[tokio::main]
+/// async fn main() {
+///   let server = topgg::hyper::listen(
+///     "127.0.0.1:8080",
+///     env!("TOPGG_WEBHOOK_PASSWORD"),
+///     |vote: topgg::Vote| async move {
+///       println!("{:?}", vote);
+///     },
+///   )
+///   .await
+///   .unwrap();
+///
+///   server.await;
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "hyper")))]
+pub async fn listen<A, S, H>(addr: A, secret: S, handler: H) -> io::Result<WebhookServer>
+where
+  A: ToSocketAddrs,
+  S: Into<WebhookSecret>,
+  H: VoteHandler,
+{
+  let listener = TcpListener::bind(addr).await?;
+  let local_addr = listener.local_addr()?;
+  let shutdown = Arc::new(Notify::new());
+  let dispatcher = VoteDispatcher::new(secret, handler);
+
+  let task = tokio::spawn({
+    let shutdown = Arc::clone(&shutdown);
+
+    async move {
+      loop {
+        let stream = tokio::select! {
+          res = listener.accept() => match res {
+            Ok((stream, _)) => stream,
+            Err(_) => {
+              sleep(ACCEPT_ERROR_DELAY).await;
+              continue;
+            }
+          },
+          _ = shutdown.notified() => break,
+        };
+
+        let dispatcher = dispatcher.clone();
+
+        tokio::spawn(async move {
+          let service = service_fn(move |req| {
+            let dispatcher = dispatcher.clone();
+
+            async move { Ok::<_, Infallible>(handle(req, &dispatcher).await) }
+          });
+
+          let _ = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), service)
+            .await;
+        });
+      }
+    }
+  });
+
+  Ok(WebhookServer {
+    local_addr,
+    shutdown,
+    task,
+  })
+}
@@ -1,9 +1,17 @@
+mod dedup;
 mod vote;
 #[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
+pub use dedup::VoteDeduplicator;
+#[cfg_attr(docsrs, doc(cfg(feature = "webhook")))]
 pub use vote::*;
 
-#[cfg(feature = "actix-web")]
-mod actix_web;
+cfg_if::cfg_if! {
+  if #[cfg(feature = "actix-web")] {
+    /// Wrapper for working with the [`actix-web`](https://actix.rs) web framework.
+    #[cfg_attr(docsrs, doc(cfg(feature = "actix-web")))]
+    pub mod actix_web;
+  }
+}
 
 #[cfg(feature = "rocket")]
 mod rocket;
@@ -23,3 +31,11 @@ cfg_if::cfg_if! {
     pub mod warp;
   }
 }
+
+cfg_if::cfg_if! {
+  if #[cfg(feature = "hyper")] {
+    /// A standalone webhook server built on top of [`hyper`](https://hyper.rs).
+    #[cfg_attr(docsrs, doc(cfg(feature = "hyper")))]
+    pub mod hyper;
+  }
+}
@@ -1,6 +1,10 @@
 use crate::snowflake;
+use core::{future::Future, hint::black_box};
 use serde::{Deserialize, Deserializer};
-use std::collections::HashMap;
+use std::{
+  collections::HashMap,
+  sync::{Arc, RwLock},
+};
 
 #[inline(always)]
 fn deserialize_is_test<'de, D>(deserializer: D) -> Result<bool, D::Error>
@@ -10,16 +14,10 @@ where
   String::deserialize(deserializer).map(|s| s == "test")
 }
 
-const fn _true() -> bool {
-  true
-}
-
-#[inline(always)]
-fn deserialize_is_server<'de, D>(deserializer: D) -> Result<bool, D::Error>
-where
-  D: Deserializer<'de>,
-{
-  Ok(String::deserialize(deserializer).is_err())
+fn decode_query_component(component: &str) -> Option<String> {
+  urlencoding::decode(&component.replace('+', " "))
+    .ok()
+    .map(|decoded| decoded.into_owned())
 }
 
 fn deserialize_query_string<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
@@ -27,15 +25,21 @@ where
   D: Deserializer<'de>,
 {
   Ok(
-    String::deserialize(deserializer)
+    Option::<String>::deserialize(deserializer)
       .map(|s| {
         let mut output = HashMap::new();
+        let s = s.unwrap_or_default();
+
+        for pair in s
+          .strip_prefix('?')
+          .unwrap_or(&s)
+          .split('&')
+          .filter(|pair| !pair.is_empty())
+        {
+          let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
 
-        for mut it in s.split('&').map(|pair| pair.split('=')) {
-          if let (Some(k), Some(v)) = (it.next(), it.next()) {
-            if let Ok(v) = urlencoding::decode(v) {
-              output.insert(k.to_owned(), v.into_owned());
-            }
+          if let (Some(k), Some(v)) = (decode_query_component(k), decode_query_component(v)) {
+            output.insert(k, v);
           }
         }
 
@@ -45,44 +49,237 @@ where
   )
 }
 
+/// The type of a dispatched [Top.gg](https://top.gg) vote event. (See [`Vote::kind`])
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VoteKind {
+  /// A real vote coming from a user.
+  Upvote,
+
+  /// A test vote coming from the bot/server owner.
+  Test,
+}
+
+/// The bot/server that received a dispatched [Top.gg](https://top.gg) vote event. (See [`Vote::target`])
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VoteTarget {
+  /// A bot, with its ID.
+  Bot(u64),
+
+  /// A server, with its ID.
+  Guild(u64),
+}
+
+impl VoteTarget {
+  /// Retrieves the ID of the bot/server that received the vote.
+  #[must_use]
+  #[inline(always)]
+  pub const fn id(&self) -> u64 {
+    match self {
+      Self::Bot(id) | Self::Guild(id) => *id,
+    }
+  }
+}
+
+#[derive(Deserialize)]
+struct RawVote {
+  #[serde(default, deserialize_with = "snowflake::deserialize_optional")]
+  bot: Option<u64>,
+
+  #[serde(default, deserialize_with = "snowflake::deserialize_optional")]
+  guild: Option<u64>,
+
+  #[serde(deserialize_with = "snowflake::deserialize")]
+  user: u64,
+
+  #[serde(deserialize_with = "deserialize_is_test", rename = "type")]
+  is_test: bool,
+
+  #[serde(default, rename = "isWeekend")]
+  is_weekend: bool,
+
+  #[serde(default, deserialize_with = "deserialize_query_string")]
+  query: HashMap<String, String>,
+}
+
+impl TryFrom<RawVote> for Vote {
+  type Error = &'static str;
+
+  fn try_from(raw: RawVote) -> Result<Self, Self::Error> {
+    let (receiver_id, is_server) = match (raw.bot, raw.guild) {
+      (Some(bot), _) => (bot, false),
+      (None, Some(guild)) => (guild, true),
+      _ => return Err("missing field `bot` or `guild`"),
+    };
+
+    Ok(Self {
+      receiver_id,
+      voter_id: raw.user,
+      is_server,
+      is_test: raw.is_test,
+      is_weekend: raw.is_weekend,
+      query: raw.query,
+    })
+  }
+}
+
 /// A struct representing a dispatched [Top.gg](https://top.gg) bot/server vote event.
 #[must_use]
 #[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "RawVote")]
 pub struct Vote {
   /// The ID of the bot/server that received a vote.
-  #[serde(
-    deserialize_with = "snowflake::deserialize",
-    alias = "bot",
-    alias = "guild"
-  )]
   pub receiver_id: u64,
 
   /// The ID of the user who voted.
-  #[serde(deserialize_with = "snowflake::deserialize", rename = "user")]
   pub voter_id: u64,
 
   /// Whether this vote's receiver is a server or not (bot otherwise).
-  #[serde(
-    default = "_true",
-    deserialize_with = "deserialize_is_server",
-    rename = "bot"
-  )]
   pub is_server: bool,
 
   /// Whether this vote is just a test coming from the bot/server owner or not. Most of the time this would be `false`.
-  #[serde(deserialize_with = "deserialize_is_test", rename = "type")]
   pub is_test: bool,
 
   /// Whether the weekend multiplier is active or not, meaning a single vote counts as two.
   /// If the dispatched event came from a server being voted, this will always be `false`.
-  #[serde(default, rename = "isWeekend")]
   pub is_weekend: bool,
 
-  /// query strings found on the vote page.
-  #[serde(default, deserialize_with = "deserialize_query_string")]
+  /// Percent-decoded query strings found on the vote page. Keys without a value are mapped to an empty string, and if a key is repeated, its last value is used.
   pub query: HashMap<String, String>,
 }
 
+impl Vote {
+  /// Retrieves the type of this vote, which is either a real [upvote][VoteKind::Upvote] or a [test][VoteKind::Test] one.
+  #[must_use]
+  #[inline(always)]
+  pub const fn kind(&self) -> VoteKind {
+    if self.is_test {
+      VoteKind::Test
+    } else {
+      VoteKind::Upvote
+    }
+  }
+
+  /// Retrieves the percent-decoded value of a query string found on the vote page, if any. (See [`query`][Vote::query])
+  #[must_use]
+  #[inline(always)]
+  pub fn query_param(&self, key: &str) -> Option<&str> {
+    self.query.get(key).map(String::as_str)
+  }
+
+  /// Retrieves the receiver of this vote, which is either a [bot][VoteTarget::Bot] or a [server][VoteTarget::Guild].
+  #[must_use]
+  #[inline(always)]
+  pub const fn target(&self) -> VoteTarget {
+    if self.is_server {
+      VoteTarget::Guild(self.receiver_id)
+    } else {
+      VoteTarget::Bot(self.receiver_id)
+    }
+  }
+}
+
+/// Checks if an incoming request's `Authorization` header value matches your webhook password in constant time, preventing timing attacks from guessing it.
+///
+/// This is already used by every web framework integration in this SDK, so you only need this when receiving votes by other means.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// # let authorization = "password";
+/// if topgg::verify_authorization(env!("TOPGG_WEBHOOK_PASSWORD"), authorization) {
+///   // respond with 200 OK...
+/// } else {
+///   // respond with 401 UNAUTHORIZED...
+/// }
+/// ```
+#[must_use]
+pub fn verify_authorization(password: &str, authorization: &str) -> bool {
+  let (password, authorization) = (password.as_bytes(), authorization.as_bytes());
+
+  // only the length of the attacker-controlled side affects how long this takes, with a length mismatch folded into the difference instead of returning early.
+  let diff =
+    authorization
+      .iter()
+      .enumerate()
+      .fold(password.len() ^ authorization.len(), |diff, (i, b)| {
+        let a = password.get(i).copied().unwrap_or_default();
+
+        black_box(diff | usize::from(a ^ b))
+      });
+
+  black_box(diff) == 0
+}
+
+/// A cheaply cloneable webhook password, used by web framework extractors to authenticate incoming [`Vote`]s.
+///
+/// Every clone shares the same password, so [rotating][WebhookSecret::rotate] it through any of them takes effect everywhere, like in a web framework's state, without having to restart your server.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::WebhookSecret;
+///
+/// let secret = WebhookSecret::new(env!("TOPGG_WEBHOOK_PASSWORD"));
+/// let state = secret.clone();
+///
+/// // later, after changing the password on Top.gg...
+/// secret.rotate("new password");
+///
+/// assert!(state.verify("new password"));
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct WebhookSecret(Arc<RwLock<Arc<str>>>);
+
+impl WebhookSecret {
+  /// Creates a new webhook secret from your webhook password.
+  #[inline(always)]
+  pub fn new<P>(password: P) -> Self
+  where
+    P: Into<String>,
+  {
+    Self(Arc::new(RwLock::new(Arc::from(password.into()))))
+  }
+
+  /// Replaces the webhook password of this secret and every clone of it. Requests being authenticated at the same time use either the old or the new password.
+  pub fn rotate<P>(&self, password: P)
+  where
+    P: Into<String>,
+  {
+    *self.0.write().unwrap() = Arc::from(password.into());
+  }
+
+  #[inline(always)]
+  pub(crate) fn password(&self) -> Arc<str> {
+    Arc::clone(&self.0.read().unwrap())
+  }
+
+  /// Checks if an incoming request's `Authorization` header value matches this secret in constant time. (See [`verify_authorization`])
+  #[must_use]
+  #[inline(always)]
+  pub fn verify(&self, authorization: &str) -> bool {
+    verify_authorization(&self.password(), authorization)
+  }
+}
+
+impl From<String> for WebhookSecret {
+  #[inline(always)]
+  fn from(password: String) -> Self {
+    Self::new(password)
+  }
+}
+
+impl From<&str> for WebhookSecret {
+  #[inline(always)]
+  fn from(password: &str) -> Self {
+    Self::new(password)
+  }
+}
+
 cfg_if::cfg_if! {
   if #[cfg(any(feature = "actix-web", feature = "rocket"))] {
     /// A struct that represents an **unauthenticated** request containing a [`Vote`] data.
@@ -120,7 +317,7 @@ cfg_if::cfg_if! {
       #[must_use]
       #[inline(always)]
       pub fn authenticate(self, password: &str) -> Option<Vote> {
-        if self.authorization == password {
+        if verify_authorization(password, &self.authorization) {
           Some(self.vote)
         } else {
           None
@@ -130,22 +327,115 @@ cfg_if::cfg_if! {
   }
 }
 
-cfg_if::cfg_if! {
-  if #[cfg(any(feature = "axum", feature = "warp"))] {
-    /// An async trait for adding an on-vote event handler to your application logic.
-    ///
-    /// It's described as follows (without [`async_trait`]'s macro expansion):
-    /// ```rust,no_run
-    /// #[async_trait::async_trait]
-    /// pub trait VoteHandler: Send + Sync + 'static {
-    ///   async fn voted(&self, vote: Vote);
-    /// }
-    /// ```
-    #[cfg_attr(docsrs, doc(cfg(any(feature = "axum", feature = "warp"))))]
-    #[async_trait::async_trait]
-    pub trait VoteHandler: Send + Sync + 'static {
-      /// Your vote handler's on-vote async callback. The endpoint will always return a 200 (OK) HTTP status code after running this method.
-      async fn voted(&self, vote: Vote);
+/// An async trait for adding an on-vote event handler to your application logic, independent of the web framework used to receive votes.
+///
+/// It's described as follows (without [`async_trait`]'s macro expansion):
+/// ```rust,no_run
+/// #[async_trait::async_trait]
+/// pub trait VoteHandler: Send + Sync + 'static {
+///   async fn voted(&self, vote: Vote);
+/// }
+/// ```
+///
+/// This trait is also implemented for any async closure that takes a [`Vote`].
+#[async_trait::async_trait]
+pub trait VoteHandler: Send + Sync + 'static {
+  /// Your vote handler's on-vote async callback. The endpoint will always return a 200 (OK) HTTP status code after running this method.
+  async fn voted(&self, vote: Vote);
+}
+
+#[async_trait::async_trait]
+impl<F, R> VoteHandler for F
+where
+  F: Fn(Vote) -> R + Send + Sync + 'static,
+  R: Future<Output = ()> + Send,
+{
+  #[inline(always)]
+  async fn voted(&self, vote: Vote) {
+    self(vote).await
+  }
+}
+
+/// Authenticates raw incoming webhook requests and dispatches their [`Vote`]s to a [`VoteHandler`].
+///
+/// This is what every web framework integration in this SDK uses under the hood, and it can be used to plug a [`VoteHandler`] into any other HTTP server.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use topgg::{Vote, VoteDispatcher};
+///
+/// # async fn example(authorization: Option<&str>, body: &[u8]) {
+/// let dispatcher = VoteDispatcher::new(env!("TOPGG_WEBHOOK_PASSWORD"), |vote: Vote| async move {
+///   println!("{:?}", vote);
+/// });
+///
+/// // for every incoming request...
+/// let status = dispatcher.dispatch(authorization, body).await;
+///
+/// // respond with `status`...
+/// # }
+/// ```
+#[must_use]
+pub struct VoteDispatcher<H: ?Sized> {
+  pub(crate) secret: WebhookSecret,
+  pub(crate) handler: Arc<H>,
+}
+
+impl<H> VoteDispatcher<H>
+where
+  H: VoteHandler,
+{
+  /// Creates a new dispatcher from your webhook password and a [`VoteHandler`].
+  #[inline(always)]
+  pub fn new<S>(secret: S, handler: H) -> Self
+  where
+    S: Into<WebhookSecret>,
+  {
+    Self {
+      secret: secret.into(),
+      handler: Arc::new(handler),
+    }
+  }
+}
+
+impl<H> VoteDispatcher<H>
+where
+  H: VoteHandler + ?Sized,
+{
+  /// Authenticates an incoming request from its `Authorization` header value and its body, then calls the [`VoteHandler`] if it's valid.
+  ///
+  /// Returns the HTTP status code to respond with, which is one of the following:
+  /// - 200 (OK) if the [`VoteHandler`] was called.
+  /// - 401 (Unauthorized) if the `Authorization` header is missing or doesn't match your webhook password.
+  /// - 400 (Bad Request) if the body is not a valid [`Vote`].
+  pub async fn dispatch(&self, authorization: Option<&str>, body: &[u8]) -> u16 {
+    if !authorization.is_some_and(|authorization| self.secret.verify(authorization)) {
+      return 401;
+    }
+
+    match serde_json::from_slice(body) {
+      Ok(vote) => {
+        self.handler.voted(vote).await;
+
+        200
+      }
+      Err(_) => 400,
+    }
+  }
+}
+
+impl<H> Clone for VoteDispatcher<H>
+where
+  H: ?Sized,
+{
+  #[inline(always)]
+  fn clone(&self) -> Self {
+    Self {
+      secret: self.secret.clone(),
+      handler: Arc::clone(&self.handler),
     }
   }
 }
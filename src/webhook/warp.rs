@@ -1,6 +1,83 @@
-use crate::{Vote, VoteHandler};
+use crate::{Vote, VoteDispatcher, VoteHandler, WebhookSecret};
 use std::sync::Arc;
-use warp::{body, header, http::StatusCode, path, Filter, Rejection, Reply};
+use warp::{
+  body, header, http::StatusCode, hyper::body::Bytes, path, reject, Filter, Rejection, Reply,
+};
+
+// votes are tiny, anything bigger than this isn't coming from Top.gg.
+const MAX_BODY_SIZE: u64 = 4096;
+
+/// A [`warp`] rejection produced by [`filter`] when the `Authorization` header is missing or doesn't match your webhook password.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(docsrs, doc(cfg(feature = "warp")))]
+pub struct Unauthorized;
+
+impl reject::Reject for Unauthorized {}
+
+/// Creates a new `warp` [`Filter`] that extracts an authenticated incoming [`Vote`], to be composed with your other routes.
+///
+/// This filter doesn't match any HTTP method or path by itself, and rejects with:
+/// - [`Unauthorized`] if the `Authorization` header is missing or doesn't match `secret`.
+/// - warp's [`PayloadTooLarge`][reject::PayloadTooLarge] or [`LengthRequired`][reject::LengthRequired] if the body is larger than 4 KiB or its length is not specified.
+/// - warp's own body rejections (like an unsupported content type or a deserialization error) if the body is not a valid [`Vote`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust,no_run
+/// use std::net::SocketAddr;
+/// use warp::{http::StatusCode, Filter, Rejection, Reply};
+///
+/// async fn recover(rejection: Rejection) -> Result<impl Reply, Rejection> {
+///   if rejection.find::<topgg::warp::Unauthorized>().is_some() {
+///     Ok(StatusCode::UNAUTHORIZED)
+///   } else {
+///     Err(rejection)
+///   }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///   // POST /webhook
+///   let webhook = warp::post()
+///     .and(warp::path("webhook"))
+///     .and(topgg::warp::filter(env!("TOPGG_WEBHOOK_PASSWORD")))
+///     .map(|vote: topgg::Vote| {
+///       println!("{:?}", vote);
+///
+///       StatusCode::OK
+///     })
+///     .recover(recover);
+///
+///   let addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+///
+///   warp::serve(webhook).run(addr).await
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "warp")))]
+pub fn filter<S>(secret: S) -> impl Filter<Extract = (Vote,), Error = Rejection> + Clone
+where
+  S: Into<WebhookSecret>,
+{
+  let secret = secret.into();
+
+  header::optional("Authorization")
+    .and_then(move |authorization: Option<String>| {
+      let authorized = authorization.is_some_and(|authorization| secret.verify(&authorization));
+
+      async move {
+        if authorized {
+          Ok(())
+        } else {
+          Err(reject::custom(Unauthorized))
+        }
+      }
+    })
+    .untuple_one()
+    .and(body::content_length_limit(MAX_BODY_SIZE))
+    .and(body::json())
+}
 
 /// Creates a new `warp` [`Filter`] for adding an on-vote event handler to your application logic.
 ///
@@ -49,24 +126,23 @@ pub fn webhook<T>(
 where
   T: VoteHandler,
 {
-  let password = Arc::new(password);
+  let dispatcher = VoteDispatcher {
+    secret: WebhookSecret::new(password),
+    handler: state,
+  };
 
   warp::post()
     .and(path(endpoint))
-    .and(header("Authorization"))
-    .and(body::json())
-    .then(move |auth: String, vote: Vote| {
-      let current_state = Arc::clone(&state);
-      let current_password = Arc::clone(&password);
+    .and(header::optional("Authorization"))
+    .and(body::content_length_limit(MAX_BODY_SIZE))
+    .and(body::bytes())
+    .then(move |authorization: Option<String>, body: Bytes| {
+      let dispatcher = dispatcher.clone();
 
       async move {
-        if auth == *current_password {
-          current_state.voted(vote).await;
+        let status = dispatcher.dispatch(authorization.as_deref(), &body).await;
 
-          StatusCode::OK
-        } else {
-          StatusCode::UNAUTHORIZED
-        }
+        StatusCode::from_u16(status).unwrap()
       }
     })
 }